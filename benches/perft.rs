@@ -0,0 +1,66 @@
+//! Times move generation on the perft suite positions, run with `cargo bench`
+use chess::chess::Chess;
+use std::time::Instant;
+
+const POSITIONS: [(&str, &str, usize, u64); 4] = [
+    (
+        "start",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        4,
+        197281,
+    ),
+    (
+        "kiwipete",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        3,
+        97862,
+    ),
+    (
+        "position 3",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        5,
+        674624,
+    ),
+    (
+        "position 4",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        3,
+        9467,
+    ),
+];
+
+fn main() {
+    for (name, fen, depth, expected) in POSITIONS {
+        let game = Chess::from_fen(fen).unwrap();
+        let start = Instant::now();
+        let nodes = game.perft(depth);
+        let elapsed = start.elapsed();
+        assert_eq!(nodes, expected, "perft({}) of {}", depth, name);
+        println!(
+            "perft {:<10} depth {}: {:>7} nodes in {:>8.1?} ({:.0} nodes/s)",
+            name,
+            depth,
+            nodes,
+            elapsed,
+            nodes as f64 / elapsed.as_secs_f64()
+        );
+    }
+
+    let mut game = Chess::from_fen(POSITIONS[1].1).unwrap();
+    let start = Instant::now();
+    let mut moves = 0;
+    for _ in 0..1000 {
+        moves += game.legal_moves().len();
+    }
+    println!(
+        "legal_moves kiwipete x1000: {} moves in {:.1?}",
+        moves,
+        start.elapsed()
+    );
+    let start = Instant::now();
+    for _ in 0..1000 {
+        game.play_san("Qf4").unwrap();
+        game.undo().unwrap();
+    }
+    println!("play_move and undo x1000: {:.1?}", start.elapsed());
+}
@@ -0,0 +1,186 @@
+use chess::chess::Chess;
+use chess::search::{Limits, Search, SearchResult};
+use std::io::stdin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// kept on the clock for the communication with the GUI
+const TIME_MARGIN: Duration = Duration::from_millis(50);
+// moves left to play with the remaining time when the GUI doesn't send movestogo
+const DEFAULT_MOVES_TO_GO: u32 = 30;
+
+/// Speaks the Universal Chess Interface over stdin and stdout, so the engine can be
+/// plugged into GUIs and engine testing tools
+fn main() {
+    let mut engine = Engine::new();
+    for line in stdin().lines() {
+        match line {
+            Ok(line) if engine.handle(&line) => (),
+            _ => break,
+        }
+    }
+    engine.stop();
+}
+
+struct Engine {
+    game: Chess,
+    search: Option<Search>, // None while the search runs on its thread
+    thread: Option<JoinHandle<Search>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl Engine {
+    fn new() -> Self {
+        let search = Search::new();
+        Engine {
+            game: new_game(),
+            stop: search.stop_flag(),
+            search: Some(search),
+            thread: None,
+        }
+    }
+
+    /// Handles a command from the GUI, returns false on quit
+    fn handle(&mut self, line: &str) -> bool {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens.first() {
+            Some(&"uci") => {
+                println!("id name {}", env!("CARGO_PKG_NAME"));
+                println!("id author {} contributors", env!("CARGO_PKG_NAME"));
+                println!("uciok");
+            }
+            Some(&"isready") => println!("readyok"),
+            Some(&"ucinewgame") => {
+                self.stop().clear();
+                self.game = new_game();
+            }
+            Some(&"position") => {
+                self.stop();
+                match parse_position(&tokens[1..]) {
+                    Ok(game) => self.game = game,
+                    Err(error) => println!("info string invalid position: {}", error),
+                }
+            }
+            Some(&"go") => {
+                self.stop();
+                let limits = parse_limits(&tokens[1..], &self.game);
+                self.go(limits, tokens.contains(&"infinite"));
+            }
+            Some(&"stop") => {
+                self.stop();
+            }
+            Some(&"quit") => return false,
+            // unknown commands are ignored as the protocol asks
+            _ => (),
+        }
+        true
+    }
+
+    /// Searches the current position on another thread, which prints
+    /// the info lines then the best move, an infinite search waits for stop to print it
+    fn go(&mut self, limits: Limits, infinite: bool) {
+        let mut search = self.search.take().expect("no search running");
+        let game = self.game.clone();
+        let stop = Arc::clone(&self.stop);
+        let start = Instant::now();
+        search.on_progress(move |result| println!("{}", info(result, start.elapsed())));
+        self.thread = Some(thread::spawn(move || {
+            let result = search.search(&game, limits);
+            while infinite && !stop.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            match result.best_move {
+                Some(move_) => println!("bestmove {}", move_),
+                None => println!("bestmove 0000"),
+            }
+            search
+        }));
+    }
+
+    /// Stops the running search, if any, once it has printed its best move
+    fn stop(&mut self) -> &mut Search {
+        if let Some(thread) = self.thread.take() {
+            self.stop.store(true, Ordering::Relaxed);
+            self.search = Some(thread.join().expect("search thread panicked"));
+            self.stop.store(false, Ordering::Relaxed);
+        }
+        self.search.as_mut().expect("no search running")
+    }
+}
+
+fn new_game() -> Chess {
+    Chess::new(String::from("White"), String::from("Black"))
+}
+
+/// Parses "startpos" or "fen <fen>", optionally followed by "moves" and UCI moves
+fn parse_position(tokens: &[&str]) -> Result<Chess, String> {
+    let moves = tokens
+        .iter()
+        .position(|token| *token == "moves")
+        .unwrap_or(tokens.len());
+    let mut game = match tokens.first() {
+        Some(&"startpos") => new_game(),
+        Some(&"fen") => Chess::from_fen(&tokens[1..moves].join(" ")).map_err(|e| e.to_string())?,
+        _ => return Err(String::from("expected startpos or fen")),
+    };
+    for uci in tokens.iter().skip(moves + 1) {
+        game.play_uci(uci)
+            .map_err(|error| format!("{}: {}", uci, error))?;
+    }
+    Ok(game)
+}
+
+/// Parses the limits of a go command, the time left on the clock is split
+/// between the moves still to play
+fn parse_limits(tokens: &[&str], game: &Chess) -> Limits {
+    let value = |name: &str| -> Option<u64> {
+        let position = tokens.iter().position(|token| *token == name)?;
+        tokens.get(position + 1)?.parse().ok()
+    };
+    let (time, increment) = match game.get_current_color() {
+        chess::piece::Color::White => (value("wtime"), value("winc")),
+        chess::piece::Color::Black => (value("btime"), value("binc")),
+    };
+    let mut limits = Limits {
+        depth: value("depth").map(|depth| depth as u32),
+        nodes: value("nodes"),
+        time: value("movetime").map(Duration::from_millis),
+    };
+    if let (Some(time), None) = (time, limits.time) {
+        let time = Duration::from_millis(time);
+        let moves_to_go =
+            value("movestogo").map_or(DEFAULT_MOVES_TO_GO, |moves| moves.max(1) as u32);
+        let budget = time / moves_to_go + Duration::from_millis(increment.unwrap_or(0)) / 2;
+        limits.time = Some(
+            budget
+                .min(time.saturating_sub(TIME_MARGIN))
+                .max(Duration::from_millis(1)),
+        );
+    }
+    limits
+}
+
+/// Returns the info line of a completed depth
+fn info(result: &SearchResult, elapsed: Duration) -> String {
+    let score = match result.mate_in() {
+        Some(moves) => format!("mate {}", moves),
+        None => format!("cp {}", result.score),
+    };
+    let millis = elapsed.as_millis() as u64;
+    let variation: Vec<String> = result
+        .principal_variation
+        .iter()
+        .map(|move_| move_.to_string())
+        .collect();
+    format!(
+        "info depth {} score {} nodes {} nps {} time {} pv {}",
+        result.depth,
+        score,
+        result.nodes,
+        result.nodes * 1000 / millis.max(1),
+        millis,
+        variation.join(" ")
+    )
+}
@@ -0,0 +1,155 @@
+use crate::piece::Color;
+
+/// One bit per square, the bit index is row * 8 + file with row 0 the 8th rank
+pub(crate) type Bitboard = u64;
+
+const KNIGHT_OFFSETS: [(isize, isize); 8] = [
+    (2, 1),
+    (2, -1),
+    (1, 2),
+    (-1, 2),
+    (-2, 1),
+    (-2, -1),
+    (-1, -2),
+    (1, -2),
+];
+
+const KING_OFFSETS: [(isize, isize); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+// a white pawn attacks towards row 0, a black pawn towards row 7
+const WHITE_PAWN_OFFSETS: [(isize, isize); 2] = [(-1, -1), (-1, 1)];
+const BLACK_PAWN_OFFSETS: [(isize, isize); 2] = [(1, -1), (1, 1)];
+
+// the first four directions go towards higher bit indexes, the last four towards lower ones
+const ROOK_DIRECTIONS: [usize; 4] = [0, 1, 4, 5];
+const BISHOP_DIRECTIONS: [usize; 4] = [2, 3, 6, 7];
+const DIRECTIONS: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 0),
+    (1, 1),
+    (1, -1),
+    (0, -1),
+    (-1, 0),
+    (-1, -1),
+    (-1, 1),
+];
+
+pub(crate) const KNIGHT_ATTACKS: [Bitboard; 64] = leaper_attacks(&KNIGHT_OFFSETS);
+pub(crate) const KING_ATTACKS: [Bitboard; 64] = leaper_attacks(&KING_OFFSETS);
+pub(crate) const PAWN_ATTACKS: [[Bitboard; 64]; 2] = [
+    leaper_attacks(&WHITE_PAWN_OFFSETS),
+    leaper_attacks(&BLACK_PAWN_OFFSETS),
+];
+const RAYS: [[Bitboard; 64]; 8] = [
+    ray_attacks(DIRECTIONS[0]),
+    ray_attacks(DIRECTIONS[1]),
+    ray_attacks(DIRECTIONS[2]),
+    ray_attacks(DIRECTIONS[3]),
+    ray_attacks(DIRECTIONS[4]),
+    ray_attacks(DIRECTIONS[5]),
+    ray_attacks(DIRECTIONS[6]),
+    ray_attacks(DIRECTIONS[7]),
+];
+
+/// Returns the bit index of a 0-indexed (row, file) square
+pub(crate) fn index(square: (isize, isize)) -> usize {
+    (square.0 * 8 + square.1) as usize
+}
+
+/// Returns the bitboard with only square set
+pub(crate) fn bit(square: (isize, isize)) -> Bitboard {
+    1 << index(square)
+}
+
+/// Returns the index of color in the per color tables, white first
+pub(crate) fn color_index(color: Color) -> usize {
+    match color {
+        Color::White => 0,
+        Color::Black => 1,
+    }
+}
+
+/// Returns the (row, file) squares set in the bitboard, from a8 to h1
+pub(crate) fn squares(mut bitboard: Bitboard) -> impl Iterator<Item = (isize, isize)> {
+    std::iter::from_fn(move || {
+        if bitboard == 0 {
+            return None;
+        }
+        let index = bitboard.trailing_zeros() as isize;
+        bitboard &= bitboard - 1;
+        Some((index / 8, index % 8))
+    })
+}
+
+/// Returns the squares a rook on square attacks, the first piece on each line included
+pub(crate) fn rook_attacks(square: (isize, isize), occupancy: Bitboard) -> Bitboard {
+    ROOK_DIRECTIONS.iter().fold(0, |attacks, direction| {
+        attacks | slide(index(square), *direction, occupancy)
+    })
+}
+
+/// Returns the squares a bishop on square attacks, the first piece on each diagonal included
+pub(crate) fn bishop_attacks(square: (isize, isize), occupancy: Bitboard) -> Bitboard {
+    BISHOP_DIRECTIONS.iter().fold(0, |attacks, direction| {
+        attacks | slide(index(square), *direction, occupancy)
+    })
+}
+
+/// Returns the ray from index in direction cut after its first occupied square
+fn slide(index: usize, direction: usize, occupancy: Bitboard) -> Bitboard {
+    let ray = RAYS[direction][index];
+    let blockers = ray & occupancy;
+    if blockers == 0 {
+        return ray;
+    }
+    let blocker = match direction < 4 {
+        true => blockers.trailing_zeros(),
+        false => 63 - blockers.leading_zeros(),
+    };
+    ray ^ RAYS[direction][blocker as usize]
+}
+
+/// Precomputes the squares reached from every square with a single offset
+const fn leaper_attacks(offsets: &[(isize, isize)]) -> [Bitboard; 64] {
+    let mut attacks = [0; 64];
+    let mut index = 0;
+    while index < 64 {
+        let (row, file) = ((index / 8) as isize, (index % 8) as isize);
+        let mut offset = 0;
+        while offset < offsets.len() {
+            let (target_row, target_file) = (row + offsets[offset].0, file + offsets[offset].1);
+            if target_row >= 0 && target_row < 8 && target_file >= 0 && target_file < 8 {
+                attacks[index] |= 1 << (target_row * 8 + target_file);
+            }
+            offset += 1;
+        }
+        index += 1;
+    }
+    attacks
+}
+
+/// Precomputes the squares from every square to the edge of the board in direction
+const fn ray_attacks(direction: (isize, isize)) -> [Bitboard; 64] {
+    let mut rays = [0; 64];
+    let mut index = 0;
+    while index < 64 {
+        let mut row = (index / 8) as isize + direction.0;
+        let mut file = (index % 8) as isize + direction.1;
+        while row >= 0 && row < 8 && file >= 0 && file < 8 {
+            rays[index] |= 1 << (row * 8 + file);
+            row += direction.0;
+            file += direction.1;
+        }
+        index += 1;
+    }
+    rays
+}
@@ -1,110 +1,254 @@
+use crate::bitboard::{
+    bishop_attacks, bit, color_index, index, rook_attacks, Bitboard, KING_ATTACKS, KNIGHT_ATTACKS,
+    PAWN_ATTACKS,
+};
+use crate::error::FenError;
 use crate::piece::Color::{Black, White};
 use crate::piece::{Color, Piece};
-use crate::square::Square;
+use crate::zobrist::KEYS;
 use colored::*;
 
 const ROWS: usize = 8;
 const FILES: usize = 8;
 
+/// The pieces are kept both in a mailbox for lookups by square
+/// and in bitboards per color and kind for attacks
 #[derive(Debug, Clone)]
 pub struct Board {
-    pub(crate) squares: Vec<Vec<Square>>,
-    white_king_position: (isize, isize),
-    black_king_position: (isize, isize),
+    squares: [Option<Piece>; ROWS * FILES],
+    pieces: [[Bitboard; 6]; 2], // indexed by color, then kind in the order of Piece
+    occupancy: [Bitboard; 2],   // every piece of a color
+    hash: u64,                  // Zobrist hash of the placement, updated with every change
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Board {
     /// Creates a new board with the standard chess configuration
     pub fn new() -> Self {
         // create board with starting configuration
-        let mut squares = Self::init_empty_board();
+        let mut board = Self::init_empty_board();
 
         // place pieces according to standard chess starting configuration
         for file in 0..FILES {
-            squares[1][file] = Square::new(Some(Piece::Pawn(Black)));
-            squares[6][file] = Square::new(Some(Piece::Pawn(White)));
+            board.set_piece(1, file as isize, Piece::Pawn(Black));
+            board.set_piece(6, file as isize, Piece::Pawn(White));
             match file {
-                4 => {
-                    Self::place_piece(&mut squares, Piece::King(Black), Piece::King(White), file);
-                }
-                3 => {
-                    Self::place_piece(&mut squares, Piece::Queen(Black), Piece::Queen(White), file);
+                4 => board.place_piece(Piece::King(Black), Piece::King(White), file),
+                3 => board.place_piece(Piece::Queen(Black), Piece::Queen(White), file),
+                0 | 7 => board.place_piece(Piece::Rook(Black), Piece::Rook(White), file),
+                2 | 5 => board.place_piece(Piece::Bishop(Black), Piece::Bishop(White), file),
+                1 | 6 => board.place_piece(Piece::Knight(Black), Piece::Knight(White), file),
+                _ => (),
+            }
+        }
+        board
+    }
+
+    /// Creates a board from the piece placement field of a FEN string
+    pub fn from_fen_placement(placement: &str) -> Result<Self, FenError> {
+        let mut board = Self::init_empty_board();
+        let mut king_positions = [None, None];
+        let ranks: Vec<&str> = placement.split('/').collect();
+        if ranks.len() != ROWS {
+            return Err(FenError::InvalidPlacement(format!(
+                "expected {} ranks, found {}",
+                ROWS,
+                ranks.len()
+            )));
+        }
+        for (row, rank) in ranks.iter().enumerate() {
+            let mut file = 0;
+            for letter in rank.chars() {
+                if let Some(empty) = letter.to_digit(10) {
+                    file += empty as usize;
+                    continue;
                 }
-                0 | 7 => {
-                    Self::place_piece(&mut squares, Piece::Rook(Black), Piece::Rook(White), file);
+                let piece = Piece::from_fen_char(letter).ok_or_else(|| {
+                    FenError::InvalidPlacement(format!("unknown piece '{}'", letter))
+                })?;
+                if file >= FILES {
+                    return Err(FenError::InvalidPlacement(format!(
+                        "rank {} has more than {} squares",
+                        ROWS - row,
+                        FILES
+                    )));
                 }
-                2 | 5 => {
-                    Self::place_piece(
-                        &mut squares,
-                        Piece::Bishop(Black),
-                        Piece::Bishop(White),
-                        file,
-                    );
+                if piece == Piece::Pawn(*piece.get_color()) && (row == 0 || row == ROWS - 1) {
+                    return Err(FenError::InvalidPlacement(format!(
+                        "pawn on rank {}",
+                        ROWS - row
+                    )));
                 }
-                1 | 6 => {
-                    Self::place_piece(
-                        &mut squares,
-                        Piece::Knight(Black),
-                        Piece::Knight(White),
-                        file,
-                    );
+                if let Piece::King(color) = piece {
+                    let index = match color {
+                        White => 0,
+                        Black => 1,
+                    };
+                    if king_positions[index].is_some() {
+                        return Err(FenError::InvalidPlacement(format!(
+                            "more than one {:?} king",
+                            color
+                        )));
+                    }
+                    king_positions[index] = Some((row as isize, file as isize));
                 }
-                _ => (),
+                board.set_piece(row as isize, file as isize, piece);
+                file += 1;
+            }
+            if file != FILES {
+                return Err(FenError::InvalidPlacement(format!(
+                    "rank {} does not have {} squares",
+                    ROWS - row,
+                    FILES
+                )));
             }
         }
-        Board {
-            squares,
-            white_king_position: (7, 4),
-            black_king_position: (0, 4),
+        match king_positions {
+            [Some(_), Some(_)] => Ok(board),
+            _ => Err(FenError::InvalidPlacement(String::from(
+                "each side needs exactly one king",
+            ))),
+        }
+    }
+
+    /// Returns the piece placement field of the FEN string for this board
+    pub fn to_fen_placement(&self) -> String {
+        let mut ranks = vec![];
+        for squares in self.squares.chunks(FILES) {
+            let mut rank = String::new();
+            let mut empty = 0;
+            for square in squares {
+                match square {
+                    None => empty += 1,
+                    Some(piece) => {
+                        if empty > 0 {
+                            rank.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        rank.push(piece.to_fen_char());
+                    }
+                }
+            }
+            if empty > 0 {
+                rank.push_str(&empty.to_string());
+            }
+            ranks.push(rank);
         }
+        ranks.join("/")
     }
 
     // Helper function for repetitive code
-    fn place_piece(squares: &mut Vec<Vec<Square>>, piece1: Piece, piece2: Piece, file: usize) {
-        squares[0][file].place_piece(piece1);
-        squares[7][file].place_piece(piece2);
+    fn place_piece(&mut self, piece1: Piece, piece2: Piece, file: usize) {
+        self.set_piece(0, file as isize, piece1);
+        self.set_piece(7, file as isize, piece2);
     }
 
     pub fn get_piece(&self, row: isize, file: isize) -> &Option<Piece> {
-        let board = &self.squares;
-        board[row as usize][file as usize].get_piece()
+        &self.squares[index((row, file))]
     }
 
+    /// Places piece on the square, replacing the piece already there
     pub fn set_piece(&mut self, row: isize, file: isize, piece: Piece) {
-        let board = &mut self.squares;
-        board[row as usize][file as usize].place_piece(piece);
+        self.remove_piece(row, file);
+        self.squares[index((row, file))] = Some(piece);
+        let (color, kind) = Self::piece_index(piece);
+        self.pieces[color][kind] |= bit((row, file));
+        self.occupancy[color] |= bit((row, file));
+        self.hash ^= KEYS.pieces[color][kind][index((row, file))];
     }
 
     pub fn remove_piece(&mut self, row: isize, file: isize) {
-        let board = &mut self.squares;
-        board[row as usize][file as usize].remove_piece();
+        if let Some(piece) = self.squares[index((row, file))].take() {
+            let (color, kind) = Self::piece_index(piece);
+            self.pieces[color][kind] &= !bit((row, file));
+            self.occupancy[color] &= !bit((row, file));
+            self.hash ^= KEYS.pieces[color][kind][index((row, file))];
+        }
     }
 
-    pub fn get_king_position(&self, color: Color) -> (isize, isize) {
-        match color {
-            Color::White => self.white_king_position,
-            Color::Black => self.black_king_position,
+    /// Moves the piece on source to destination, capturing the piece there
+    pub fn move_piece(&mut self, source: (isize, isize), destination: (isize, isize)) {
+        if let Some(piece) = self.squares[index(source)] {
+            self.remove_piece(source.0, source.1);
+            self.set_piece(destination.0, destination.1, piece);
         }
     }
 
-    pub fn set_king_position(&mut self, color: Color, position: (isize, isize)) {
-        match color {
-            Color::White => self.white_king_position = position,
-            Color::Black => self.black_king_position = position,
+    pub fn get_king_position(&self, color: Color) -> (isize, isize) {
+        let kings = self.pieces[color_index(color)][0];
+        let index = kings.trailing_zeros() as isize;
+        (index / 8, index % 8)
+    }
+
+    /// Returns the Zobrist hash of the piece placement
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the squares occupied by the pieces of color
+    pub fn occupancy(&self, color: Color) -> Bitboard {
+        self.occupancy[color_index(color)]
+    }
+
+    /// Returns the squares the piece would attack from square, friendly pieces included
+    pub fn attacks(&self, square: (isize, isize), piece: Piece) -> Bitboard {
+        let occupancy = self.occupancy[0] | self.occupancy[1];
+        match piece {
+            Piece::King(_) => KING_ATTACKS[index(square)],
+            Piece::Queen(_) => rook_attacks(square, occupancy) | bishop_attacks(square, occupancy),
+            Piece::Rook(_) => rook_attacks(square, occupancy),
+            Piece::Bishop(_) => bishop_attacks(square, occupancy),
+            Piece::Knight(_) => KNIGHT_ATTACKS[index(square)],
+            Piece::Pawn(color) => PAWN_ATTACKS[color_index(color)][index(square)],
         }
     }
 
-    /// Returns a new board with all squares set to None
-    fn init_empty_board() -> Vec<Vec<Square>> {
-        let mut squares: Vec<Vec<Square>> = vec![];
-        for _ in 0..ROWS {
-            let mut row = vec![];
-            for _ in 0..FILES {
-                row.push(Square::new(None));
-            }
-            squares.push(row);
+    /// Returns the squares of the pieces equal to piece, the bit row * 8 + file for each
+    pub fn pieces(&self, piece: Piece) -> Bitboard {
+        let (color, kind) = Self::piece_index(piece);
+        self.pieces[color][kind]
+    }
+
+    /// Returns true if any piece of color attacks the square
+    pub fn is_attacked(&self, square: (isize, isize), color: Color) -> bool {
+        let pieces = &self.pieces[color_index(color)];
+        let occupancy = self.occupancy[0] | self.occupancy[1];
+        let straight = pieces[1] | pieces[2];
+        let diagonal = pieces[1] | pieces[3];
+        // a piece of color attacks the square if the same piece on the square attacks it
+        KING_ATTACKS[index(square)] & pieces[0] != 0
+            || KNIGHT_ATTACKS[index(square)] & pieces[4] != 0
+            || PAWN_ATTACKS[color_index(color.other())][index(square)] & pieces[5] != 0
+            || rook_attacks(square, occupancy) & straight != 0
+            || bishop_attacks(square, occupancy) & diagonal != 0
+    }
+
+    /// Returns the indexes of the piece's bitboard, by color and kind
+    pub(crate) fn piece_index(piece: Piece) -> (usize, usize) {
+        let kind = match piece {
+            Piece::King(_) => 0,
+            Piece::Queen(_) => 1,
+            Piece::Rook(_) => 2,
+            Piece::Bishop(_) => 3,
+            Piece::Knight(_) => 4,
+            Piece::Pawn(_) => 5,
+        };
+        (color_index(*piece.get_color()), kind)
+    }
+
+    /// Returns a new board without any piece
+    fn init_empty_board() -> Self {
+        Board {
+            squares: [None; ROWS * FILES],
+            pieces: [[0; 6]; 2],
+            occupancy: [0; 2],
+            hash: 0,
         }
-        squares
     }
 
     /// Prints the board to the terminal in a user-friendly format
@@ -116,14 +260,13 @@ impl Board {
     /// that can be printed to the terminal or
     /// transferred over the network
     pub fn chess_print(&self) -> String {
-        let board = &self.squares;
-        let mut repr = format!("{}", "  a  b  c  d  e  f  g  h  \n");
-        for row in 0..ROWS {
+        let mut repr = String::from("  a  b  c  d  e  f  g  h  \n");
+        for (row, squares) in self.squares.chunks(FILES).enumerate() {
             repr = format!("{}{}", repr, 8 - row);
-            for file in 0..FILES {
-                let character = match &board[row][file].piece {
+            for (file, square) in squares.iter().enumerate() {
+                let character = match square {
                     None => String::from("   "),
-                    Some(piece) => String::from(format!(" {} ", piece)),
+                    Some(piece) => format!(" {} ", piece),
                 };
                 if (row + file) % 2 == 0 {
                     repr = format!("{}{}", repr, character.on_white());
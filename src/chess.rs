@@ -1,12 +1,22 @@
+use crate::bitboard::{bit, squares};
 use crate::board::Board;
-use crate::error::GameState;
+use crate::error::{FenError, MoveError, PgnError};
+use crate::evaluation::{Evaluator, StandardEvaluator};
+use crate::moves::Move;
+use crate::outcome::{GameResult, GameStatus, MoveOutcome, Termination};
+use crate::pgn::{read_pgn, write_pgn};
 use crate::piece::{Color, Piece};
 use crate::player::Player;
+use crate::san::{move_to_san, parse_san, piece_letter};
+use crate::search::{Limits, Search, MAX_LEVEL};
+use crate::square::{parse_square, square_name};
+use crate::zobrist::KEYS;
 use std::io::{stdin, stdout, Write};
 
 const PLAYERS: usize = 2;
 const ROWS: isize = 8;
 const COLS: isize = 8;
+const START_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
 const LEGAL_KNIGHT_MOVES: [(isize, isize); 8] = [
     (2, 1),
     (2, -1),
@@ -29,19 +39,53 @@ const LEGAL_KING_MOVES: [(isize, isize); 8] = [
     (1, -1),
 ];
 
-const LEGAL_PAWN_MOVES: [(isize, isize); 4] = [(1, 0), (2, 0), (1, 1), (1, -1)];
-
+#[derive(Clone)]
 pub struct Chess {
     chessboard: Board,
     players: [Player; 2],
     current_turn: usize,
     castling_rights: [[bool; 2]; PLAYERS], // queen side and king side castling per player
+    en_passant: Option<(isize, isize)>,    // square passed over by the last double pawn push
+    halfmove_clock: u32,                   // halfmoves since the last capture or pawn move
+    fullmove_number: u32,                  // starts at 1, incremented after black's move
+    history: Vec<String>,                  // SAN of every move played
+    positions: Vec<u64>,                   // hash of every position reached, for repetitions
+    undo_stack: Vec<PlayedMove>,           // moves played, with the state they were played from
+    redo_stack: Vec<Move>,                 // moves taken back, the last one is redone first
+    result: Option<(GameResult, Termination)>, // set once the game is over
+    initial_fen: Option<String>, // set when the game did not start from the standard position
+    tags: Vec<(String, String)>, // tag pairs read from PGN, written back on export
+}
+
+/// State changed by make_a_move, used to probe moves without altering the game
+#[derive(Clone)]
+pub(crate) struct Snapshot {
+    chessboard: Board,
+    castling_rights: [[bool; 2]; PLAYERS],
+    en_passant: Option<(isize, isize)>,
+}
+
+/// Commands typed in the terminal game
+enum Command {
+    Move(String),
+    DrawOffer,
+    Resign,
+    TakeBack,
+}
+
+/// A move played with play_move and the state it was played from, used by undo
+#[derive(Clone)]
+struct PlayedMove {
+    move_: Move,
+    snapshot: Snapshot,
+    halfmove_clock: u32,
+    fullmove_number: u32,
 }
 
 impl Chess {
     /// Returns a new instance of the game
     pub fn new(player1: String, player2: String) -> Self {
-        Chess {
+        let mut game = Chess {
             chessboard: Board::new(),
             players: [
                 Player::new(player1, Color::White),
@@ -49,110 +93,941 @@ impl Chess {
             ],
             current_turn: 0,
             castling_rights: [[true, true], [true, true]],
+            en_passant: None,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            history: vec![],
+            positions: vec![],
+            undo_stack: vec![],
+            redo_stack: vec![],
+            result: None,
+            initial_fen: None,
+            tags: vec![],
+        };
+        game.positions.push(game.hash());
+        game
+    }
+
+    /// Returns a game set up from a position in Forsyth-Edwards Notation
+    /// The halfmove clock and fullmove number may be omitted
+    pub fn from_fen(fen: &str) -> Result<Self, FenError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() != 4 && fields.len() != 6 {
+            return Err(FenError::WrongFieldCount(fields.len()));
+        }
+        let chessboard = Board::from_fen_placement(fields[0])?;
+        let current_turn = match fields[1] {
+            "w" => 0,
+            "b" => 1,
+            side => return Err(FenError::InvalidSideToMove(String::from(side))),
+        };
+        let mut castling_rights = [[false, false], [false, false]];
+        if fields[2] != "-" {
+            for letter in fields[2].chars() {
+                let right = match letter {
+                    'Q' => &mut castling_rights[0][0],
+                    'K' => &mut castling_rights[0][1],
+                    'q' => &mut castling_rights[1][0],
+                    'k' => &mut castling_rights[1][1],
+                    _ => return Err(FenError::InvalidCastling(String::from(fields[2]))),
+                };
+                if *right {
+                    return Err(FenError::InvalidCastling(String::from(fields[2])));
+                }
+                *right = true;
+            }
+        }
+        // the square a pawn of the side not to move just passed over with a double push:
+        // its row, the pawn and the rows the pawn stands on and started from
+        let (row, pawn, pawn_row, start_row) = match current_turn {
+            0 => (2, Piece::Pawn(Color::Black), 3, 1),
+            _ => (5, Piece::Pawn(Color::White), 4, 6),
+        };
+        let en_passant = match fields[3] {
+            "-" => None,
+            square => match parse_square(square) {
+                Some(position)
+                    if position.0 == row
+                        && *chessboard.get_piece(pawn_row, position.1) == Some(pawn)
+                        && chessboard.get_piece(row, position.1).is_none()
+                        && chessboard.get_piece(start_row, position.1).is_none() =>
+                {
+                    Some(position)
+                }
+                _ => return Err(FenError::InvalidEnPassant(String::from(square))),
+            },
+        };
+        let (halfmove_clock, fullmove_number) = match fields.len() {
+            6 => (
+                fields[4]
+                    .parse()
+                    .map_err(|_| FenError::InvalidHalfmoveClock(String::from(fields[4])))?,
+                match fields[5].parse() {
+                    Ok(number) if number > 0 => number,
+                    _ => return Err(FenError::InvalidFullmoveNumber(String::from(fields[5]))),
+                },
+            ),
+            _ => (0, 1),
+        };
+        let mut game = Chess {
+            chessboard,
+            players: [
+                Player::new(String::from("White"), Color::White),
+                Player::new(String::from("Black"), Color::Black),
+            ],
+            current_turn,
+            castling_rights,
+            en_passant,
+            halfmove_clock,
+            fullmove_number,
+            history: vec![],
+            positions: vec![],
+            undo_stack: vec![],
+            redo_stack: vec![],
+            result: None,
+            initial_fen: None,
+            tags: vec![],
+        };
+        // the side to move could capture the king
+        if game.is_under_check(game.get_current_color().other()) {
+            return Err(FenError::InvalidPlacement(String::from(
+                "the side not to move is in check",
+            )));
         }
+        // the PGN FEN tag needs all six fields, even if the clocks were omitted
+        let fen = game.to_fen();
+        if fen != START_FEN {
+            game.initial_fen = Some(fen);
+        }
+        game.positions.push(game.hash());
+        // the position may already be checkmate, stalemate or a dead draw
+        game.update_result(None);
+        Ok(game)
+    }
+
+    /// Returns the current position in Forsyth-Edwards Notation
+    pub fn to_fen(&self) -> String {
+        let side = match self.get_current_color() {
+            Color::White => "w",
+            Color::Black => "b",
+        };
+        let mut castling = String::new();
+        for (right, letter) in [
+            (self.castling_rights[0][1], 'K'),
+            (self.castling_rights[0][0], 'Q'),
+            (self.castling_rights[1][1], 'k'),
+            (self.castling_rights[1][0], 'q'),
+        ] {
+            if right {
+                castling.push(letter);
+            }
+        }
+        if castling.is_empty() {
+            castling.push('-');
+        }
+        let en_passant = match self.en_passant {
+            Some(square) => square_name(square).expect("the en passant square is on the board"),
+            None => String::from("-"),
+        };
+        format!(
+            "{} {} {} {} {} {}",
+            self.chessboard.to_fen_placement(),
+            side,
+            castling,
+            en_passant,
+            self.halfmove_clock,
+            self.fullmove_number
+        )
+    }
+
+    /// Returns the game read from the first game of a PGN text, replaying every move
+    /// of the main line through the move validator.
+    /// The first illegal move is reported with its ply and the reason it was rejected.
+    pub fn from_pgn(pgn: &str) -> Result<Self, PgnError> {
+        let record = read_pgn(pgn)?;
+        let mut game = match record.get_tag("FEN") {
+            Some(fen) => Chess::from_fen(fen).map_err(PgnError::InvalidFen)?,
+            None => Chess::new(String::from("White"), String::from("Black")),
+        };
+        if let Some(name) = record.get_tag("White") {
+            game.players[0] = Player::new(name.clone(), Color::White);
+        }
+        if let Some(name) = record.get_tag("Black") {
+            game.players[1] = Player::new(name.clone(), Color::Black);
+        }
+        game.tags = record.tags.clone();
+        for (index, san) in record.moves.iter().enumerate() {
+            let illegal = |reason| PgnError::IllegalMove {
+                ply: index + 1,
+                san: san.clone(),
+                reason,
+            };
+            let move_ = parse_san(&mut game, san).map_err(illegal)?;
+            game.play_move(move_.source, move_.destination, move_.promotion)
+                .map_err(illegal)?;
+        }
+        let result = record.result.as_deref().and_then(GameResult::from_pgn);
+        match (game.result, result) {
+            (Some((board, _)), Some(token)) if board != token => {
+                return Err(PgnError::ResultMismatch { token, board });
+            }
+            // games ended by resignation, agreement, a draw claim or on time keep their result
+            (None, Some(result)) => {
+                let on_time = record
+                    .get_tag("Termination")
+                    .is_some_and(|termination| termination.eq_ignore_ascii_case("time forfeit"));
+                let claim = game.draw_status().and_then(|status| status.termination());
+                let termination = match result {
+                    _ if on_time => Termination::Timeout,
+                    GameResult::Draw => claim.unwrap_or(Termination::Agreement),
+                    _ => Termination::Resignation,
+                };
+                game.result = Some((result, termination));
+            }
+            _ => (),
+        }
+        Ok(game)
     }
 
     /// Driver code for the game (1v1 terminal)
     pub fn start(&mut self) {
-        loop {
+        let mut search = None; // created for the first computer move
+        while self.result.is_none() {
             self.chessboard.pretty_print();
             let current_player = &self.players[self.current_turn];
-            let color = current_player.get_color().clone();
-            print!("{}' turn. \n", current_player.get_name());
-            let (source, destination) = match Self::get_move() {
-                Ok((source, destination)) => (source, destination),
-                Err(GameState::DrawOffer(_)) => {
-                    let msg = &format!(
-                        "{} offered a draw!\nDo you want to accept? (Y / N)",
-                        current_player.get_name()
-                    )[..];
-                    let response = String::new();
-                    match Self::get_position(msg, response) {
-                        Err(GameState::DrawRejected) => {
-                            println!("Draw rejected.");
+            println!("{}' turn. ", current_player.get_name());
+            let color = *current_player.get_color();
+            let result = match current_player.get_level() {
+                Some(level) => {
+                    let search = search.get_or_insert_with(Search::new);
+                    match search.search(self, Limits::level(level)).best_move {
+                        Some(move_) => {
+                            self.play_move(move_.source, move_.destination, move_.promotion)
+                        }
+                        None => {
+                            println!("{} has no legal move.", current_player.get_name());
+                            break;
+                        }
+                    }
+                }
+                None => {
+                    let input = match Self::get_command() {
+                        Command::Move(input) => input,
+                        Command::DrawOffer => {
+                            self.offer_draw();
+                            continue;
+                        }
+                        Command::Resign => {
+                            self.resign(color).ok();
                             continue;
                         }
-                        Err(GameState::GameOver(msg)) => {
-                            println!("{}", msg);
-                            return;
-                        },
-                        Err(GameState::InvalidMove(msg)) => {
-                            println!("{}", msg);
+                        Command::TakeBack => {
+                            self.take_back();
                             continue;
                         }
-                        _ => ((-1, -1), (-1, -1)),
+                    };
+                    match self.play_input(&input, None) {
+                        // the pawn reached the last rank, ask which piece it becomes
+                        Err(error @ MoveError::PromotionRequired { .. }) => {
+                            match Self::get_promotion(color) {
+                                Some(piece) => self.play_input(&input, Some(piece)),
+                                None => Err(error),
+                            }
+                        }
+                        result => result,
                     }
                 }
-                Err(GameState::Resignation) => {
-                    println!("Game Over! {} resigned", current_player.get_name());
-                    break;
-                },
-                Err(GameState::InvalidMove(msg)) => {
-                    println!("{}", msg);
-                    continue;
-                }
-                _ => ((-1, -1), (-1, -1)),
             };
-            match self.make_a_move(source, destination) {
-                Ok(()) => {
-                    if self.is_under_checkmate(self.players[self.current_turn].get_color().other())
+            match result {
+                Ok(outcome) => {
+                    println!(
+                        "{} played {}",
+                        self.players[(self.current_turn + 1) % PLAYERS].get_name(),
+                        self.history.last().unwrap()
+                    );
+                    if let GameStatus::ThreefoldRepetition | GameStatus::FiftyMoveRule =
+                        outcome.status
                     {
-                        println!(
-                            "Checkmate! {} wins!",
-                            self.players[self.current_turn].get_name()
+                        let player = &self.players[self.current_turn];
+                        let msg = &format!(
+                            "{} can claim a draw ({:?}). Claim? (Y / N)",
+                            player.get_name(),
+                            outcome.status
                         );
-                        break;
+                        let claim = match player.get_level() {
+                            Some(_) => self.computer_accepts_draw(*player.get_color()),
+                            None => Self::get_confirmation(msg),
+                        };
+                        if claim {
+                            self.claim_draw().ok();
+                        }
                     }
-                    self.current_turn = (self.current_turn + 1) % 2;
-                    if self.is_under_stalemate(color) {
-                        println!("Draw by stalemate!");
-                        break;
+                }
+                Err(error) => println!("Invalid Move: {}", error),
+            }
+        }
+        self.chessboard.pretty_print();
+        match self.result {
+            Some((GameResult::Draw, termination)) => println!("Draw by {}!", termination),
+            Some((result, termination)) => {
+                let winner = match result.winner() {
+                    Some(Color::White) => &self.players[0],
+                    _ => &self.players[1],
+                };
+                println!("Game over! {} wins by {}!", winner.get_name(), termination);
+            }
+            None => (),
+        }
+        println!("{}", self.to_pgn());
+    }
+
+    /// Makes the player to move make a draw offer, answered by the opponent
+    fn offer_draw(&mut self) {
+        let player = &self.players[self.current_turn];
+        let opponent = &self.players[(self.current_turn + 1) % PLAYERS];
+        let msg = &format!(
+            "{} offered a draw!\nDo you want to accept? (Y / N)",
+            player.get_name()
+        );
+        let accepted = match opponent.get_level() {
+            Some(_) => self.computer_accepts_draw(*opponent.get_color()),
+            None => Self::get_confirmation(msg),
+        };
+        match accepted {
+            true => {
+                self.agree_draw().ok();
+            }
+            false => println!("Draw rejected."),
+        }
+    }
+
+    /// Takes back the last move, and the computer's reply before it when playing the computer
+    fn take_back(&mut self) {
+        let opponent = &self.players[(self.current_turn + 1) % PLAYERS];
+        let moves = match opponent.get_level() {
+            // otherwise the computer plays its reply again
+            Some(_) if self.undo_stack.len() >= 2 => 2,
+            _ => 1,
+        };
+        for _ in 0..moves {
+            match self.undo() {
+                Ok(move_) => println!("Took back {}", move_),
+                Err(error) => println!("{}", error),
+            }
+        }
+    }
+
+    /// Returns true if the computer playing color takes a draw, when it is behind
+    fn computer_accepts_draw(&self, color: Color) -> bool {
+        StandardEvaluator.evaluate(&self.chessboard, color) < 0
+    }
+
+    /// Makes color played by the computer at strength level, from 1 to MAX_LEVEL,
+    /// or by a human if level is None
+    pub fn set_computer(&mut self, color: Color, level: Option<u32>) {
+        let index = match color {
+            Color::White => 0,
+            Color::Black => 1,
+        };
+        self.players[index].set_level(level.map(|level| level.clamp(1, MAX_LEVEL)));
+    }
+
+    /// Plays a move for the side to move without any terminal I/O.
+    /// `promotion` is the piece a pawn reaching the last rank is promoted to.
+    /// On success the turn passes to the opponent and the returned outcome
+    /// describes the captured piece and whether the opponent is in check,
+    /// checkmate or stalemate.
+    pub fn play_move(
+        &mut self,
+        source: (isize, isize),
+        destination: (isize, isize),
+        promotion: Option<Piece>,
+    ) -> Result<MoveOutcome, MoveError> {
+        if self.result.is_some() {
+            return Err(MoveError::GameOver);
+        }
+        let pawn_move = matches!(self.get_piece(source.0, source.1), Some(Piece::Pawn(_)));
+        let played = PlayedMove {
+            move_: Move::new(source, destination, promotion),
+            snapshot: self.snapshot(),
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+        };
+        let captured = self.make_a_move(source, destination, promotion)?;
+        // the SAN describes the legal move from the position before it
+        let after = self.snapshot();
+        self.restore(played.snapshot.clone());
+        let san = move_to_san(self, source, destination, promotion);
+        self.restore(after);
+        self.undo_stack.push(played);
+        self.redo_stack.clear();
+        self.halfmove_clock = match pawn_move || captured.is_some() {
+            true => 0,
+            false => self.halfmove_clock + 1,
+        };
+        if self.get_current_color() == Color::Black {
+            self.fullmove_number += 1;
+        }
+        self.current_turn = (self.current_turn + 1) % PLAYERS;
+        self.positions.push(self.hash());
+        let outcome = self.update_result(captured);
+        let suffix = match (outcome.checkmate, outcome.check) {
+            (true, _) => "#",
+            (false, true) => "+",
+            _ => "",
+        };
+        self.history.push(format!("{}{}", san, suffix));
+        Ok(outcome)
+    }
+
+    /// Returns the status of the current position for the side to move
+    /// and ends the game if it is over
+    fn update_result(&mut self, captured: Option<Piece>) -> MoveOutcome {
+        let color = self.get_current_color();
+        let check = self.is_under_check(color);
+        let checkmate = self.is_under_checkmate(color);
+        let stalemate = !check && self.is_under_stalemate(color);
+        let mut outcome = MoveOutcome::new(captured, check, checkmate, stalemate);
+        if !checkmate && !stalemate {
+            if let Some(status) = self.draw_status() {
+                outcome.status = status;
+            }
+        }
+        if outcome.status.is_game_over() {
+            let result = match checkmate {
+                true => GameResult::win_for(color.other()),
+                false => GameResult::Draw,
+            };
+            self.result = outcome.status.termination().map(|reason| (result, reason));
+        }
+        outcome
+    }
+
+    /// Returns the draw by insufficient material, repetition or the move rules the current
+    /// position allows, if any. Insufficient material, fivefold repetition and the
+    /// seventy-five move rule end the game,
+    /// threefold repetition and the fifty move rule only allow a claim
+    fn draw_status(&self) -> Option<GameStatus> {
+        let repetitions = self.repetitions();
+        if self.is_insufficient_material() {
+            Some(GameStatus::InsufficientMaterial)
+        } else if repetitions >= 5 {
+            Some(GameStatus::FivefoldRepetition)
+        } else if self.halfmove_clock >= 150 {
+            Some(GameStatus::SeventyFiveMoveRule)
+        } else if repetitions >= 3 {
+            Some(GameStatus::ThreefoldRepetition)
+        } else if self.halfmove_clock >= 100 {
+            Some(GameStatus::FiftyMoveRule)
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if neither side can checkmate: king against king, king and a single
+    /// minor piece against king, or kings with bishops all standing on the same colored squares
+    fn is_insufficient_material(&self) -> bool {
+        let mut knights = 0;
+        let mut bishop_squares = [false; 2];
+        for row in 0..ROWS {
+            for file in 0..COLS {
+                match self.get_piece(row, file) {
+                    None | Some(Piece::King(_)) => (),
+                    Some(Piece::Knight(_)) => knights += 1,
+                    Some(Piece::Bishop(_)) => bishop_squares[((row + file) % 2) as usize] = true,
+                    Some(_) => return false,
+                }
+            }
+        }
+        match knights {
+            0 => !(bishop_squares[0] && bishop_squares[1]),
+            1 => !bishop_squares[0] && !bishop_squares[1],
+            _ => false,
+        }
+    }
+
+    /// Returns the hash of every position reached in the game, the current one last
+    pub(crate) fn positions(&self) -> &[u64] {
+        &self.positions
+    }
+
+    /// Returns the number of times the current position has been reached
+    fn repetitions(&self) -> usize {
+        let current = self.hash();
+        self.positions
+            .iter()
+            .filter(|position| **position == current)
+            .count()
+    }
+
+    /// Returns the Zobrist hash of the position: placement, side to move, castling rights
+    /// and the en passant square if the side to move can legally capture on it.
+    /// Positions that are the same for the repetition rules have the same hash
+    pub fn hash(&self) -> u64 {
+        let mut hash = self.chessboard.hash();
+        if self.get_current_color() == Color::Black {
+            hash ^= KEYS.black_to_move;
+        }
+        for (color, rights) in self.castling_rights.iter().enumerate() {
+            for (side, right) in rights.iter().enumerate() {
+                if *right {
+                    hash ^= KEYS.castling_rights[color][side];
+                }
+            }
+        }
+        let en_passant = self
+            .en_passant
+            .filter(|square| self.can_capture_en_passant(*square));
+        if let Some(square) = en_passant {
+            hash ^= KEYS.en_passant[square.1 as usize];
+        }
+        hash
+    }
+
+    /// Returns true if a pawn of the side to move can capture en passant on square
+    /// without leaving its king in check
+    fn can_capture_en_passant(&self, square: (isize, isize)) -> bool {
+        let color = self.get_current_color();
+        let row = match color {
+            Color::White => square.0 + 1,
+            Color::Black => square.0 - 1,
+        };
+        [square.1 - 1, square.1 + 1].into_iter().any(|file| {
+            if *self.get_piece(row, file) != Some(Piece::Pawn(color)) {
+                return false;
+            }
+            let mut board = self.chessboard.clone();
+            board.remove_piece(row, square.1);
+            board.move_piece((row, file), square);
+            !board.is_attacked(board.get_king_position(color), color.other())
+        })
+    }
+
+    /// Returns true if the side to move may claim a draw by threefold repetition
+    /// or the fifty move rule
+    pub fn can_claim_draw(&self) -> bool {
+        self.result.is_none() && self.draw_status().is_some()
+    }
+
+    /// Ends the game in a draw claimed by the side to move
+    pub fn claim_draw(&mut self) -> Result<(), MoveError> {
+        if !self.can_claim_draw() {
+            return Err(MoveError::NoDrawToClaim);
+        }
+        let termination = self.draw_status().and_then(|status| status.termination());
+        self.result = termination.map(|termination| (GameResult::Draw, termination));
+        Ok(())
+    }
+
+    /// Takes back the last move played, restoring the board, castling rights,
+    /// en passant square, clocks and turn. A finished game is resumed.
+    /// Returns the move taken back, it can be played again with redo
+    pub fn undo(&mut self) -> Result<Move, MoveError> {
+        let played = self.undo_stack.pop().ok_or(MoveError::NothingToUndo)?;
+        self.restore(played.snapshot);
+        self.halfmove_clock = played.halfmove_clock;
+        self.fullmove_number = played.fullmove_number;
+        self.current_turn = (self.current_turn + 1) % PLAYERS;
+        self.history.pop();
+        self.positions.pop();
+        self.result = None;
+        self.redo_stack.push(played.move_);
+        Ok(played.move_)
+    }
+
+    /// Plays again the last move taken back with undo
+    pub fn redo(&mut self) -> Result<MoveOutcome, MoveError> {
+        let move_ = *self.redo_stack.last().ok_or(MoveError::NothingToRedo)?;
+        // playing a move clears the redo stack, keep it aside
+        let mut redo_stack = std::mem::take(&mut self.redo_stack);
+        let outcome = self.play_move(move_.source, move_.destination, move_.promotion);
+        // a move that could not be played stays to be redone
+        if outcome.is_ok() {
+            redo_stack.pop();
+        }
+        self.redo_stack = redo_stack;
+        outcome
+    }
+
+    /// Plays a move for the side to move given in Standard Algebraic Notation,
+    /// e.g. "Nf3", "exd5", "O-O-O", "e8=Q+" or "Rad1"
+    pub fn play_san(&mut self, san: &str) -> Result<MoveOutcome, MoveError> {
+        let move_ = parse_san(self, san)?;
+        self.play_move(move_.source, move_.destination, move_.promotion)
+    }
+
+    /// Plays a move for the side to move given in UCI long algebraic notation, e.g. "e2e4" or "e7e8q"
+    pub fn play_uci(&mut self, uci: &str) -> Result<MoveOutcome, MoveError> {
+        let move_ = Move::from_uci(uci)?;
+        self.play_move(move_.source, move_.destination, move_.promotion)
+    }
+
+    /// Returns the SAN of a legal move for the side to move, with its check or checkmate suffix
+    pub fn to_san(
+        &mut self,
+        source: (isize, isize),
+        destination: (isize, isize),
+        promotion: Option<Piece>,
+    ) -> String {
+        let san = move_to_san(self, source, destination, promotion);
+        let snapshot = self.snapshot();
+        if self.make_a_move(source, destination, promotion).is_err() {
+            self.restore(snapshot);
+            return san;
+        }
+        let color = self.get_current_color().other();
+        self.current_turn = (self.current_turn + 1) % PLAYERS;
+        let suffix = match (self.is_under_check(color), self.is_under_checkmate(color)) {
+            (_, true) => "#",
+            (true, false) => "+",
+            _ => "",
+        };
+        self.current_turn = (self.current_turn + 1) % PLAYERS;
+        self.restore(snapshot);
+        format!("{}{}", san, suffix)
+    }
+
+    /// Returns the SAN of every move played so far
+    pub fn get_moves(&self) -> &Vec<String> {
+        &self.history
+    }
+
+    /// Ends the game with color resigning, unless it is already over
+    pub fn resign(&mut self, color: Color) -> Result<(), MoveError> {
+        self.end((GameResult::win_for(color.other()), Termination::Resignation))
+    }
+
+    /// Ends the game in a draw agreed by both players, unless it is already over
+    pub fn agree_draw(&mut self) -> Result<(), MoveError> {
+        self.end((GameResult::Draw, Termination::Agreement))
+    }
+
+    /// Ends the game lost by color running out of time, unless it is already over
+    pub fn timeout(&mut self, color: Color) -> Result<(), MoveError> {
+        self.end((GameResult::win_for(color.other()), Termination::Timeout))
+    }
+
+    /// Sets the result of a game in progress
+    fn end(&mut self, result: (GameResult, Termination)) -> Result<(), MoveError> {
+        if self.result.is_some() {
+            return Err(MoveError::GameOver);
+        }
+        self.result = Some(result);
+        Ok(())
+    }
+
+    /// Returns the result of the game and how it ended, None while it is in progress
+    pub fn result(&self) -> Option<(GameResult, Termination)> {
+        self.result
+    }
+
+    /// Returns the game so far in Portable Game Notation
+    pub fn to_pgn(&self) -> String {
+        let result = match self.result {
+            Some((result, _)) => result.to_string(),
+            None => String::from("*"),
+        };
+        let tag = |name: &str, default: &str| match self.tags.iter().find(|(tag, _)| tag == name) {
+            Some((_, value)) => value.clone(),
+            None => String::from(default),
+        };
+        let mut tags = vec![
+            ("Event", tag("Event", "Casual Game")),
+            ("Site", tag("Site", "?")),
+            ("Date", tag("Date", "????.??.??")),
+            ("Round", tag("Round", "?")),
+            ("White", self.players[0].get_name().clone()),
+            ("Black", self.players[1].get_name().clone()),
+            ("Result", result.clone()),
+        ];
+        if let Some(fen) = &self.initial_fen {
+            tags.push(("SetUp", String::from("1")));
+            tags.push(("FEN", fen.clone()));
+        }
+        for (name, value) in &self.tags {
+            if !tags.iter().any(|(tag, _)| tag == name) {
+                tags.push((name, value.clone()));
+            }
+        }
+        let current_ply = (self.fullmove_number as usize - 1) * 2 + self.current_turn;
+        let first_ply = current_ply.saturating_sub(self.history.len());
+        write_pgn(&tags, first_ply, &self.history, &result)
+    }
+
+    /// Returns the board with the pieces of the current position
+    pub fn board(&self) -> &Board {
+        &self.chessboard
+    }
+
+    /// Returns the color of the side to move
+    pub fn get_current_color(&self) -> Color {
+        *self.players[self.current_turn].get_color()
+    }
+
+    /// Returns every legal move for the side to move, including castling,
+    /// en passant and one move per promotion piece
+    pub fn legal_moves(&self) -> Vec<Move> {
+        self.clone().generate_legal_moves()
+    }
+
+    /// Returns the legal moves of the piece on square, empty if it is not the side to move's piece
+    pub fn legal_moves_from(&self, square: (isize, isize)) -> Vec<Move> {
+        self.clone().generate_legal_moves_from(square, false)
+    }
+
+    /// Returns the number of leaf nodes of the legal move tree at depth,
+    /// used to check move generation against known counts
+    pub fn perft(&self, depth: usize) -> u64 {
+        self.clone().perft_helper(depth)
+    }
+
+    /// Returns the perft count at depth - 1 below each legal move
+    pub fn divide(&self, depth: usize) -> Vec<(Move, u64)> {
+        let mut game = self.clone();
+        if depth == 0 {
+            return vec![];
+        }
+        let mut counts = vec![];
+        for move_ in game.generate_legal_moves() {
+            let snapshot = game.snapshot();
+            game.apply_move(move_);
+            counts.push((move_, game.perft_helper(depth - 1)));
+            game.undo_applied_move(snapshot);
+        }
+        counts
+    }
+
+    /// Counts the leaf nodes at depth, the game is left untouched
+    fn perft_helper(&mut self, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let moves = self.generate_legal_moves();
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+        let mut nodes = 0;
+        for move_ in moves {
+            let snapshot = self.snapshot();
+            self.apply_move(move_);
+            nodes += self.perft_helper(depth - 1);
+            self.undo_applied_move(snapshot);
+        }
+        nodes
+    }
+
+    /// Plays a legal move and passes the turn without recording it or looking for game ends
+    pub(crate) fn apply_move(&mut self, move_: Move) {
+        if self
+            .make_a_move(move_.source, move_.destination, move_.promotion)
+            .is_ok()
+        {
+            self.current_turn = (self.current_turn + 1) % PLAYERS;
+        }
+    }
+
+    /// Takes back a move played with apply_move
+    pub(crate) fn undo_applied_move(&mut self, snapshot: Snapshot) {
+        self.restore(snapshot);
+        self.current_turn = (self.current_turn + 1) % PLAYERS;
+    }
+
+    /// Generates the legal moves for the side to move, the game is left untouched
+    pub(crate) fn generate_legal_moves(&mut self) -> Vec<Move> {
+        let mut moves = vec![];
+        for square in squares(self.chessboard.occupancy(self.get_current_color())) {
+            moves.append(&mut self.generate_legal_moves_from(square, false));
+        }
+        moves
+    }
+
+    /// Generates the legal captures and promotions for the side to move, the game is left untouched
+    pub(crate) fn generate_legal_captures(&mut self) -> Vec<Move> {
+        let mut moves = vec![];
+        for square in squares(self.chessboard.occupancy(self.get_current_color())) {
+            moves.append(&mut self.generate_legal_moves_from(square, true));
+        }
+        moves
+    }
+
+    /// Generates the legal moves of the piece on square, only captures and promotions
+    /// if captures_only is set, the game is left untouched
+    fn generate_legal_moves_from(
+        &mut self,
+        square: (isize, isize),
+        captures_only: bool,
+    ) -> Vec<Move> {
+        let piece = match self.get_piece(square.0, square.1) {
+            Some(piece) if *piece.get_color() == self.get_current_color() => *piece,
+            _ => return vec![],
+        };
+        let color = *piece.get_color();
+        let last_row = match color {
+            Color::White => 0,
+            Color::Black => 7,
+        };
+        let mut moves = vec![];
+        let enemy = self.chessboard.occupancy(color.other());
+        for destination in self.candidate_destinations(square, piece) {
+            let capture = enemy & bit(destination) != 0
+                || (piece == Piece::Pawn(color) && self.en_passant == Some(destination));
+            if captures_only
+                && !capture
+                && !(piece == Piece::Pawn(color) && destination.0 == last_row)
+            {
+                continue;
+            }
+            if let Piece::Pawn(_) = piece {
+                if destination.0 == last_row {
+                    for promotion in [
+                        Piece::Queen(color),
+                        Piece::Rook(color),
+                        Piece::Bishop(color),
+                        Piece::Knight(color),
+                    ] {
+                        if self.is_legal_move(square, destination, Some(promotion)) {
+                            moves.push(Move::new(square, destination, Some(promotion)));
+                        }
                     }
+                    continue;
                 }
-                Err(e) => match e {
-                    GameState::InvalidMove(msg) => println!("Invalid Move: {}", msg),
-                    GameState::InvalidDestination(msg) => println!("Invalid Destination: {}", msg),
-                    GameState::InvalidSource(msg) => println!("Invalid Source: {}", msg),
-                    GameState::KingUnderCheck(msg) => println!("King under check. {}", msg),
-                    GameState::InvalidPromotion(msg) => println!("Invalid Promotion: {}", msg),
-                    GameState::GameOver(msg) => println!("Game over! {}", msg),
-                    _ => (),
-                },
+            }
+            if self.is_legal_move(square, destination, None) {
+                moves.push(Move::new(square, destination, None));
             }
         }
+        moves
     }
 
-    /// Driver code for the game (1v1 terminal)
+    /// Returns the on-board squares the piece could reach from square,
+    /// ignoring checks and castling conditions
+    fn candidate_destinations(&self, square: (isize, isize), piece: Piece) -> Vec<(isize, isize)> {
+        let color = *piece.get_color();
+        let own = self.chessboard.occupancy(color);
+        let attacks = self.chessboard.attacks(square, piece);
+        let destinations = match piece {
+            Piece::Pawn(_) => {
+                let enemy = self.chessboard.occupancy(color.other());
+                let en_passant = self.en_passant.map_or(0, bit);
+                let direction = match color {
+                    Color::White => -1,
+                    Color::Black => 1,
+                };
+                // single and double pushes, make_a_move checks the squares are empty
+                let pushes = [1, 2]
+                    .iter()
+                    .map(|rows| (square.0 + direction * rows, square.1))
+                    .filter(|push| (0..ROWS).contains(&push.0))
+                    .fold(0, |pushes, push| pushes | bit(push));
+                attacks & (enemy | en_passant) | pushes
+            }
+            // castling moves the king two squares along its row
+            Piece::King(_) => {
+                attacks
+                    | [square.1 - 2, square.1 + 2]
+                        .iter()
+                        .filter(|file| (0..COLS).contains(*file))
+                        .fold(0, |castling, file| castling | bit((square.0, *file)))
+            }
+            _ => attacks,
+        };
+        squares(destinations & !own).collect()
+    }
+
+    /// Returns true if the move is legal for the side to move, the game is left untouched
+    fn is_legal_move(
+        &mut self,
+        source: (isize, isize),
+        destination: (isize, isize),
+        promotion: Option<Piece>,
+    ) -> bool {
+        let snapshot = self.snapshot();
+        let legal = self.make_a_move(source, destination, promotion).is_ok();
+        self.restore(snapshot);
+        legal
+    }
+
+    /// Saves the state changed by make_a_move
+    pub(crate) fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            chessboard: self.chessboard.clone(),
+            castling_rights: self.castling_rights,
+            en_passant: self.en_passant,
+        }
+    }
+
+    /// Restores the state saved by snapshot
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.chessboard = snapshot.chessboard;
+        self.castling_rights = snapshot.castling_rights;
+        self.en_passant = snapshot.en_passant;
+    }
+
+    /// Validates and applies a move for the side to move
+    /// Returns the captured piece, if any
     fn make_a_move(
         &mut self,
         source: (isize, isize),
         destination: (isize, isize),
-    ) -> Result<(), GameState> {
+        promotion: Option<Piece>,
+    ) -> Result<Option<Piece>, MoveError> {
         let piece = self._validate_move_generic(source, destination)?;
-        let destination_piece = self.get_piece(destination.0, destination.1).clone();
-        let initial_king_position = self
-            .chessboard
-            .get_king_position(*(self.players[self.current_turn].get_color()));
-        let initial_castling_rights = self.castling_rights.clone();
+        if let Some(promotion) = promotion {
+            if piece != Piece::Pawn(*piece.get_color()) {
+                return Err(MoveError::InvalidPromotion {
+                    source,
+                    destination,
+                    promotion,
+                });
+            }
+        }
+        let destination_piece = *self.get_piece(destination.0, destination.1);
+        let initial_castling_rights = self.castling_rights;
+        let initial_en_passant = self.en_passant;
+        let en_passant_capture = self.is_en_passant_capture(piece, source, destination);
+        let captured = match en_passant_capture {
+            true => Some(Piece::Pawn(piece.get_color().other())),
+            false => destination_piece,
+        };
         match piece {
-            Piece::Pawn(color) => self.move_pawn(color, source, destination, false)?,
-            Piece::Rook(color) => self.move_rook(color, source, destination)?,
+            Piece::Pawn(color) => self.move_pawn(color, source, destination, promotion)?,
+            Piece::Rook(_color) => self.move_rook(source, destination)?,
             Piece::Knight(_color) => self.move_knight(source, destination)?,
             Piece::Bishop(_color) => self.move_bishop(source, destination)?,
-            Piece::Queen(color) => self.move_queen(color, source, destination)?,
+            Piece::Queen(_color) => self.move_queen(source, destination)?,
             Piece::King(color) => self.move_king(color, source, destination)?,
         }
+        self.update_castling_rights(source, destination);
+        // move_pawn sets the en passant square, any other move clears it
+        if piece != Piece::Pawn(*piece.get_color()) {
+            self.en_passant = None;
+        }
         if self.is_under_check(*piece.get_color()) {
+            self.en_passant = initial_en_passant;
             self.revert_game_state(
                 source,
                 destination,
                 piece,
                 destination_piece,
-                initial_king_position,
+                en_passant_capture,
                 initial_castling_rights,
             )?;
         }
-        Ok(())
+        Ok(captured)
+    }
+
+    /// Returns true if moving piece from source to destination captures en passant
+    fn is_en_passant_capture(
+        &self,
+        piece: Piece,
+        source: (isize, isize),
+        destination: (isize, isize),
+    ) -> bool {
+        let color = *piece.get_color();
+        piece == Piece::Pawn(color)
+            && source.1 != destination.1
+            && self.en_passant == Some(destination)
+            && *self.get_piece(source.0, destination.1) == Some(Piece::Pawn(color.other()))
     }
 
     /// Reverts the game state (should be called if a move leads to or maintains check for the current player)
@@ -162,10 +1037,18 @@ impl Chess {
         destination: (isize, isize),
         piece: Piece,
         destination_piece: Option<Piece>,
-        initial_king_position: (isize, isize),
+        en_passant_capture: bool,
         initial_castling_rights: [[bool; 2]; 2],
-    ) -> Result<(), GameState> {
-        self.chessboard.set_piece(source.0, source.1, piece.clone());
+    ) -> Result<(), MoveError> {
+        // an en passant capture removed the pawn beside the source, put it back
+        if en_passant_capture {
+            self.chessboard.set_piece(
+                source.0,
+                destination.1,
+                Piece::Pawn(piece.get_color().other()),
+            );
+        }
+        self.chessboard.set_piece(source.0, source.1, piece);
         match destination_piece.is_some() {
             true => {
                 self.chessboard
@@ -173,12 +1056,11 @@ impl Chess {
             }
             false => self.chessboard.remove_piece(destination.0, destination.1),
         }
-        self.chessboard
-            .set_king_position(*piece.get_color(), initial_king_position);
         self.castling_rights = initial_castling_rights;
-        Err(GameState::KingUnderCheck(format!(
-            "Cannot move! King is/will be under check"
-        )))
+        Err(MoveError::KingInCheck {
+            source,
+            destination,
+        })
     }
 
     /// Validations:
@@ -190,36 +1072,36 @@ impl Chess {
         &mut self,
         source: (isize, isize),
         destination: (isize, isize),
-    ) -> Result<Piece, GameState> {
+    ) -> Result<Piece, MoveError> {
         if destination.1 > COLS - 1
             || destination.1 < 0
             || destination.0 > ROWS - 1
             || destination.0 < 0
         {
-            return Err(GameState::InvalidDestination(format!(
-                "Destination square out of the board: {:?}",
-                destination
-            )));
+            return Err(MoveError::OutOfBoard(destination));
         }
         if source.1 > COLS - 1 || source.1 < 0 || source.0 > ROWS - 1 || source.0 < 0 {
-            return Err(GameState::InvalidSource(format!(
-                "Source square out of the board: {:?}",
-                source
-            )));
+            return Err(MoveError::OutOfBoard(source));
         }
         let piece = self.get_piece(source.0, source.1);
         if piece.is_none() {
-            return Err(GameState::InvalidSource(format!("No piece")));
+            return Err(MoveError::EmptySource(source));
         }
         let piece = piece.unwrap();
         if piece.get_color() != self.players[self.current_turn].get_color() {
-            return Err(GameState::InvalidMove(format!("Not your turn")));
+            return Err(MoveError::NotYourTurn {
+                square: source,
+                piece,
+            });
         }
-        let destination_piece = self.get_piece(destination.0, destination.1).clone();
-        if destination_piece.is_some()
-            && destination_piece.unwrap().get_color() == piece.get_color()
-        {
-            return Err(GameState::InvalidMove(format!("Can't capture your own piece")));
+        if let Some(destination_piece) = *self.get_piece(destination.0, destination.1) {
+            if destination_piece.get_color() == piece.get_color() {
+                return Err(MoveError::CaptureOwnPiece {
+                    source,
+                    destination,
+                    piece: destination_piece,
+                });
+            }
         }
         Ok(piece)
     }
@@ -230,8 +1112,9 @@ impl Chess {
         color: Color,
         source: (isize, isize),
         destination: (isize, isize),
-        check: bool,
-    ) -> Result<(), GameState> {
+        promotion: Option<Piece>,
+    ) -> Result<(), MoveError> {
+        let piece = Piece::Pawn(color);
         let (x, starting_x, front_square) = match color {
             Color::White => (2, 6, 1),
             Color::Black => (-2, 1, -1),
@@ -244,78 +1127,112 @@ impl Chess {
                     .is_some()
                     || self.get_piece(destination.0, destination.1).is_some()
                 {
-                    return Err(GameState::InvalidMove(format!(
-                        "Can't move to {:?}, obstacles between source and destination",
-                        destination
-                    )));
+                    return Err(MoveError::Blocked {
+                        piece,
+                        source,
+                        destination,
+                    });
                 }
             } else if source.0 == destination.0 + front_square {
+                // pawns only capture diagonally
                 if self.get_piece(destination.0, destination.1).is_some() {
-                    return Err(GameState::InvalidMove(format!(
-                        "Can't move to {:?}, cannot capture this piece",
-                        destination
-                    )));
+                    return Err(MoveError::Blocked {
+                        piece,
+                        source,
+                        destination,
+                    });
                 }
             } else {
-                return Err(GameState::InvalidMove(format!(
-                    "Can't move to {:?}, Pawn moves one square ahead or captures diagonally",
-                    destination
-                )));
+                return Err(MoveError::IllegalPieceMove {
+                    piece,
+                    source,
+                    destination,
+                });
             }
         // Capturing move
         } else if (source.1 == destination.1 - 1 || source.1 == destination.1 + 1)
             && source.0 == destination.0 + front_square
         {
             let destination_piece = self.get_piece(destination.0, destination.1);
-            if destination_piece.is_none() {
-                return Err(GameState::InvalidMove(format!(
-                    "Can't move to {:?}, capturing move should have a piece at destination",
-                    destination
-                )));
+            if destination_piece.is_none()
+                && !self.is_en_passant_capture(Piece::Pawn(color), source, destination)
+            {
+                return Err(MoveError::IllegalPieceMove {
+                    piece,
+                    source,
+                    destination,
+                });
             }
         } else {
-            return Err(GameState::InvalidMove(format!("Invalid pawn move!")));
+            return Err(MoveError::IllegalPieceMove {
+                piece,
+                source,
+                destination,
+            });
+        }
+        self.promote_pawn(color, source, destination, promotion)?;
+        if self.is_en_passant_capture(Piece::Pawn(color), source, destination) {
+            // the captured pawn sits beside the source, not on the destination
+            self.chessboard.remove_piece(source.0, destination.1);
         }
-        self.promote_pawn(color, source, destination, check)?;
         self._move_piece(source, destination);
+        // a double push can be captured en passant on the next move
+        self.en_passant = match (source.0 - destination.0).abs() == 2 {
+            true => Some((destination.0 + front_square, destination.1)),
+            false => None,
+        };
         Ok(())
     }
 
     /// Promotes the pawn to the required piece otherwise returns an error
+    /// Only the kind of `promotion` is used, the promoted piece keeps the pawn's color
     fn promote_pawn(
         &mut self,
         color: Color,
         source: (isize, isize),
         destination: (isize, isize),
-        check: bool,
-    ) -> Result<(), GameState> {
+        promotion: Option<Piece>,
+    ) -> Result<(), MoveError> {
         if (destination.0 == 0 && color == Color::White)
-            || (destination.0 == 7 && color == Color::Black) && !check
+            || (destination.0 == 7 && color == Color::Black)
         {
-            let mut piece = String::new();
-            println!("Enter Promotion (Q|R|N|B):");
-            stdin()
-                .read_line(&mut piece)
-                .expect("Oops! Something went wrong. Please restart.");
-            let piece = piece.trim();
-            match piece {
-                "Q" => self
-                    .chessboard
-                    .set_piece(source.0, source.1, Piece::Queen(color)),
-                "R" => self
-                    .chessboard
-                    .set_piece(source.0, source.1, Piece::Rook(color)),
-                "N" => self
-                    .chessboard
-                    .set_piece(source.0, source.1, Piece::Knight(color)),
-                "B" => self
-                    .chessboard
-                    .set_piece(source.0, source.1, Piece::Bishop(color)),
-                _ => {
-                    println!("Invalid promotion");
-                    return Err(GameState::InvalidPromotion(format!("Invalid promotion")));
+            match promotion {
+                Some(Piece::Queen(_)) => {
+                    self.chessboard
+                        .set_piece(source.0, source.1, Piece::Queen(color))
+                }
+                Some(Piece::Rook(_)) => {
+                    self.chessboard
+                        .set_piece(source.0, source.1, Piece::Rook(color))
+                }
+                Some(Piece::Knight(_)) => {
+                    self.chessboard
+                        .set_piece(source.0, source.1, Piece::Knight(color))
+                }
+                Some(Piece::Bishop(_)) => {
+                    self.chessboard
+                        .set_piece(source.0, source.1, Piece::Bishop(color))
+                }
+                Some(promotion) => {
+                    return Err(MoveError::InvalidPromotion {
+                        source,
+                        destination,
+                        promotion,
+                    });
+                }
+                None => {
+                    return Err(MoveError::PromotionRequired {
+                        source,
+                        destination,
+                    });
                 }
             }
+        } else if let Some(promotion) = promotion {
+            return Err(MoveError::InvalidPromotion {
+                source,
+                destination,
+                promotion,
+            });
         }
         Ok(())
     }
@@ -323,15 +1240,12 @@ impl Chess {
     /// Moves the rook if the move is valid
     fn move_rook(
         &mut self,
-        color: Color,
         source: (isize, isize),
         destination: (isize, isize),
-    ) -> Result<(), GameState> {
+    ) -> Result<(), MoveError> {
         // validate its either in the same row or same column
         if source.0 != destination.0 && source.1 != destination.1 {
-            return Err(GameState::InvalidMove(format!(
-                "Invalid move. Rook moves in the same file or same row"
-            )));
+            return Err(self.illegal_piece_move(source, destination));
         }
         match source.0 == destination.0 {
             true => {
@@ -375,19 +1289,6 @@ impl Chess {
                 }
             }
         }
-        // update castling rights
-        match color {
-            Color::White => match source.1 {
-                0 => self.castling_rights[0][0] = false,
-                7 => self.castling_rights[0][1] = false,
-                _ => (),
-            },
-            Color::Black => match source.1 {
-                0 => self.castling_rights[1][0] = false,
-                7 => self.castling_rights[1][1] = false,
-                _ => (),
-            },
-        }
         self._move_piece(source, destination);
         Ok(())
     }
@@ -401,7 +1302,7 @@ impl Chess {
         start: isize,
         end: isize,
         direction: &str,
-    ) -> Result<(), GameState> {
+    ) -> Result<(), MoveError> {
         for index in start..end {
             let piece = if direction == "ROW" {
                 self.get_piece(source.0, index)
@@ -409,10 +1310,7 @@ impl Chess {
                 self.get_piece(index, source.1)
             };
             if piece.is_some() {
-                return Err(GameState::InvalidMove(format!(
-                    "Can't move to {:?}",
-                    destination
-                )));
+                return Err(self.blocked(source, destination));
             }
         }
         Ok(())
@@ -424,17 +1322,14 @@ impl Chess {
         // color validation is done in _validate_move_generic
         source: (isize, isize),
         destination: (isize, isize),
-    ) -> Result<(), GameState> {
+    ) -> Result<(), MoveError> {
         let diff = (destination.0 - source.0, destination.1 - source.1);
         match LEGAL_KNIGHT_MOVES.contains(&diff) {
             true => {
                 self._move_piece(source, destination);
                 Ok(())
             }
-            false => Err(GameState::InvalidMove(format!(
-                "Can't move to {:?}",
-                destination
-            ))),
+            false => Err(self.illegal_piece_move(source, destination)),
         }
     }
 
@@ -443,15 +1338,13 @@ impl Chess {
         &mut self,
         source: (isize, isize),
         destination: (isize, isize),
-    ) -> Result<(), GameState> {
+    ) -> Result<(), MoveError> {
         // Color is not needed because color validation has been
         // completed in validate_move_generic
         if source.0 + source.1 != destination.0 + destination.1
             && source.0 - source.1 != destination.0 - destination.1
         {
-            return Err(GameState::InvalidMove(format!(
-                "Invalid move. Target is not on the same diagonal as the bishop"
-            )));
+            return Err(self.illegal_piece_move(source, destination));
         }
         match source.0 > destination.0 {
             true => match source.1 > destination.1 {
@@ -499,20 +1392,17 @@ impl Chess {
         start: isize,
         end: isize,
         direction: &str,
-    ) -> Result<(), GameState> {
+    ) -> Result<(), MoveError> {
         for index in start..end {
             let piece = match direction {
                 "top-left" => self.get_piece(source.0 - index, source.1 - index),
                 "top-right" => self.get_piece(source.0 - index, source.1 + index),
                 "down-left" => self.get_piece(source.0 + index, source.1 - index),
                 "down-right" => self.get_piece(source.0 + index, source.1 + index),
-                _ => return Err(GameState::InvalidMove(format!("Invalid move"))),
+                _ => return Err(self.illegal_piece_move(source, destination)),
             };
             if piece.is_some() {
-                return Err(GameState::InvalidMove(format!(
-                    "Can't move to {:?}. Bishop move blocked by another piece!",
-                    destination,
-                )));
+                return Err(self.blocked(source, destination));
             }
         }
         Ok(())
@@ -521,18 +1411,17 @@ impl Chess {
     /// Moves the queen if possible otherwise returns Error
     fn move_queen(
         &mut self,
-        color: Color,
         source: (isize, isize),
         destination: (isize, isize),
-    ) -> Result<(), GameState> {
+    ) -> Result<(), MoveError> {
         if source.0 == destination.0 || source.1 == destination.1 {
-            self.move_rook(color, source, destination)
+            self.move_rook(source, destination)
         } else if source.0 + source.1 == destination.0 + destination.1
             || source.0 - source.1 == destination.0 - destination.1
         {
             self.move_bishop(source, destination)
         } else {
-            Err(GameState::InvalidMove(format!("Invalid queen move.")))
+            Err(self.illegal_piece_move(source, destination))
         }
     }
 
@@ -542,89 +1431,51 @@ impl Chess {
         color: Color,
         source: (isize, isize),
         destination: (isize, isize),
-    ) -> Result<(), GameState> {
+    ) -> Result<(), MoveError> {
         for move_ in &LEGAL_KING_MOVES {
             if source.0 + move_.0 == destination.0 && source.1 + move_.1 == destination.1 {
                 self.remove_castling_rights(&color);
                 self._move_piece(source, destination);
-                self.chessboard.set_king_position(color, destination);
                 return Ok(());
             }
         }
 
-        // Castling logic
-        if source.1 - destination.1 == -2 && source.0 == destination.0 {
-            // castling
-            if color == Color::White {
-                if self.castling_rights[0][1]
-                    && self.get_piece(source.0, source.1 + 1).is_none()
-                    && self.get_piece(source.0, source.1 + 2).is_none()
-                {
-                    self.king_castling_helper(
-                        source,
-                        destination,
-                        (0, 1),
-                        color,
-                        (source.0, source.1 + 3),
-                        (source.0, source.1 + 1),
-                    )?;
-                     return Ok(());
-
-                }
-            } else {
-                if self.castling_rights[1][1]
-                    && self.get_piece(source.0, source.1 + 1).is_none()
-                    && self.get_piece(source.0, source.1 + 2).is_none()
-                {
-                    self.king_castling_helper(
-                        source,
-                        destination,
-                        (1, 1),
-                        color,
-                        (source.0, source.1 + 3),
-                        (source.0, source.1 + 1),
-                    )?;
-                     return Ok(());
-
-                }
-            }
-        } else if source.1 - destination.1 == 3 && source.0 == destination.0 {
-            if color == Color::White {
-                if self.castling_rights[0][0]
-                    && self.get_piece(source.0, source.1 - 1).is_none()
-                    && self.get_piece(source.0, source.1 - 2).is_none()
-                    && self.get_piece(source.0, source.1 - 3).is_none()
-                {
-                    self.king_castling_helper(
-                        source,
-                        destination,
-                        (0, 0),
-                        color,
-                        (source.0, source.1 - 4),
-                        (source.0, source.1 - 2),
-                    )?;
-                     return Ok(());
-
-                }
-            } else {
-                if self.castling_rights[1][0]
-                    && self.get_piece(source.0, source.1 - 1).is_none()
-                    && self.get_piece(source.0, source.1 - 2).is_none()
-                    && self.get_piece(source.0, source.1 - 3).is_none()
-                {
-                    self.king_castling_helper(
-                        source,
-                        destination,
-                        (1, 0),
-                        color,
-                        (source.0, source.1 - 4),
-                        (source.0, source.1 - 2),
-                    )?;
-                     return Ok(());
-                }
+        // Castling logic: the king moves two squares from its home square towards the rook
+        let (side, home_row) = match color {
+            Color::White => (0, 7),
+            Color::Black => (1, 0),
+        };
+        if source == (home_row, 4) && destination.0 == home_row {
+            if destination.1 == 6
+                && self.castling_rights[side][1]
+                && self.get_piece(source.0, source.1 + 1).is_none()
+                && self.get_piece(source.0, source.1 + 2).is_none()
+            {
+                self.king_castling_helper(
+                    source,
+                    destination,
+                    color,
+                    (source.0, source.1 + 3),
+                    (source.0, source.1 + 1),
+                )?;
+                return Ok(());
+            } else if destination.1 == 2
+                && self.castling_rights[side][0]
+                && self.get_piece(source.0, source.1 - 1).is_none()
+                && self.get_piece(source.0, source.1 - 2).is_none()
+                && self.get_piece(source.0, source.1 - 3).is_none()
+            {
+                self.king_castling_helper(
+                    source,
+                    destination,
+                    color,
+                    (source.0, source.1 - 4),
+                    (source.0, source.1 - 1),
+                )?;
+                return Ok(());
             }
         }
-        Err(GameState::InvalidMove(format!("Invalid King move")))
+        Err(self.illegal_piece_move(source, destination))
     }
 
     /// Castles the king if possible otherwise returns an error
@@ -632,18 +1483,60 @@ impl Chess {
         &mut self,
         source: (isize, isize),
         destination: (isize, isize),
-        castling_rights: (usize, usize),
         color: Color,
         rook_source: (isize, isize),
         rook_destination: (isize, isize),
-    ) -> Result<(), GameState> {
+    ) -> Result<(), MoveError> {
+        // the king may not castle out of, through or into check
+        let (start, end) = match source.1 < destination.1 {
+            true => (source.1, destination.1),
+            false => (destination.1, source.1),
+        };
+        for file in start..=end {
+            if self.is_square_attacked((source.0, file), color.other()) {
+                return Err(MoveError::CastlingThroughCheck((source.0, file)));
+            }
+        }
+        if *self.get_piece(rook_source.0, rook_source.1) != Some(Piece::Rook(color)) {
+            return Err(self.illegal_piece_move(source, destination));
+        }
         self._move_piece(source, destination);
         self._move_piece(rook_source, rook_destination);
-        self.castling_rights[castling_rights.0][castling_rights.1] = false;
-        self.chessboard.set_king_position(color, destination);
+        self.remove_castling_rights(&color);
         Ok(())
     }
 
+    /// Returns the error for the piece on source not moving that way
+    fn illegal_piece_move(&self, source: (isize, isize), destination: (isize, isize)) -> MoveError {
+        MoveError::IllegalPieceMove {
+            piece: self.get_piece(source.0, source.1).unwrap(),
+            source,
+            destination,
+        }
+    }
+
+    /// Returns the error for the piece on source being blocked on its way to destination
+    fn blocked(&self, source: (isize, isize), destination: (isize, isize)) -> MoveError {
+        MoveError::Blocked {
+            piece: self.get_piece(source.0, source.1).unwrap(),
+            source,
+            destination,
+        }
+    }
+
+    /// Removes the castling right of a rook that leaves or is captured on its home square
+    fn update_castling_rights(&mut self, source: (isize, isize), destination: (isize, isize)) {
+        for square in [source, destination] {
+            match square {
+                (7, 0) => self.castling_rights[0][0] = false,
+                (7, 7) => self.castling_rights[0][1] = false,
+                (0, 0) => self.castling_rights[1][0] = false,
+                (0, 7) => self.castling_rights[1][1] = false,
+                _ => (),
+            }
+        }
+    }
+
     /// Removes castling rights for side color
     /// Should be called only when a King is moved
     fn remove_castling_rights(&mut self, color: &Color) {
@@ -660,408 +1553,122 @@ impl Chess {
     /// Moves a piece from source to destination
     /// Bounds checking should be done by the caller
     fn _move_piece(&mut self, source: (isize, isize), destination: (isize, isize)) {
-        let piece = self.get_piece(source.0, source.1).unwrap();
-        let squares = &mut self.chessboard.squares;
-        squares[destination.0 as usize][destination.1 as usize].place_piece(piece.clone());
-        squares[source.0 as usize][source.1 as usize].remove_piece();
+        self.chessboard.move_piece(source, destination);
     }
 
     /// Returns true if the King is under check
-    fn is_under_check(&mut self, color: Color) -> bool {
+    pub(crate) fn is_under_check(&self, color: Color) -> bool {
         let king_position = self.chessboard.get_king_position(color);
-        let other_color = color.clone().other();
-        self.is_under_check_by_rook_queen(king_position, other_color)
-            || self.is_under_check_by_bishop_queen(king_position, other_color)
-            || self.is_under_check_by_knight(king_position, other_color)
-            || self.is_under_check_by_pawn(king_position, other_color)
-    }
-
-    /// Returns true if the King at king_position is under check by a rook or queen of opposite color
-    fn is_under_check_by_rook_queen(&self, king_position: (isize, isize), color: Color) -> bool {
-        let mut row = king_position.0;
-        let mut file = king_position.1;
-        // check right
-        while file < 7 {
-            file += 1;
-            match self.is_under_attack_by_rqb(color, row, file, "rq") {
-                Ok(true) => return true,
-                Err(_) => break,
-                _ => continue,
-            }
-        }
-        // check left
-        file = king_position.1;
-        while file > 0 {
-            file -= 1;
-            match self.is_under_attack_by_rqb(color, row, file, "rq") {
-                Ok(true) => return true,
-                Err(_) => break,
-                _ => continue,
-            }
-        }
-        // check up
-        file = king_position.1;
-        while row < 7 {
-            row += 1;
-            match self.is_under_attack_by_rqb(color, row, file, "rq") {
-                Ok(true) => return true,
-                Err(_) => break,
-                _ => continue,
-            }
-        }
-        // check down
-        row = king_position.0;
-        while row > 0 {
-            row -= 1;
-            match self.is_under_attack_by_rqb(color, row, file, "rq") {
-                Ok(true) => return true,
-                Err(_) => break,
-                _ => continue,
-            }
-        }
-        false
-    }
-
-    /// Returns true if piece at (row, file) is one of opponent's "pieces"
-    fn is_under_attack_by_rqb(
-        &self,
-        color: Color,
-        row: isize,
-        file: isize,
-        pieces: &str,
-    ) -> Result<bool, GameState> {
-        let piece = self.get_piece(row, file);
-        if piece.is_some() {
-            return if pieces == "rq"
-                && (piece.unwrap() == Piece::Rook(color) || piece.unwrap() == Piece::Queen(color))
-                || pieces == "bq"
-                    && (piece.unwrap() == Piece::Bishop(color)
-                        || piece.unwrap() == Piece::Queen(color))
-            {
-                Ok(true)
-            } else {
-                Err(GameState::OK) // this is necessary because of the loop in caller
-            };
-        }
-        Ok(false)
-    }
-
-    /// Returns true if king is exposed to opponents bishop or queen along the diagonals
-    fn is_under_check_by_bishop_queen(&self, king_position: (isize, isize), color: Color) -> bool {
-        let mut row = king_position.0;
-        let mut file = king_position.1;
-        // check right up
-        while row < 7 && file < 7 {
-            row += 1;
-            file += 1;
-            match self.is_under_attack_by_rqb(color, row, file, "bq") {
-                Ok(true) => return true,
-                Err(_) => break,
-                _ => continue,
-            }
-        }
-        // check left up
-        row = king_position.0;
-        file = king_position.1;
-        while row < 7 && file > 0 {
-            row += 1;
-            file -= 1;
-            match self.is_under_attack_by_rqb(color, row, file, "bq") {
-                Ok(true) => return true,
-                Err(_) => break,
-                _ => continue,
-            }
-        }
-        // check right down
-        row = king_position.0;
-        file = king_position.1;
-        while row > 0 && file < 7 {
-            row -= 1;
-            file += 1;
-            match self.is_under_attack_by_rqb(color, row, file, "bq") {
-                Ok(true) => return true,
-                Err(_) => break,
-                _ => continue,
-            }
-        }
-        // check left down
-        row = king_position.0;
-        file = king_position.1;
-        while row > 0 && file > 0 {
-            row -= 1;
-            file -= 1;
-            match self.is_under_attack_by_rqb(color, row, file, "bq") {
-                Ok(true) => return true,
-                Err(_) => break,
-                _ => continue,
-            }
-        }
-        false
+        self.is_square_attacked(king_position, color.other())
     }
 
-    /// Returns true if the king_position is under check by any Knight
-    /// color: opponent color
-    fn is_under_check_by_knight(&self, king_position: (isize, isize), color: Color) -> bool {
-        for move_ in LEGAL_KNIGHT_MOVES {
-            let possible_enemy_knight_position =
-                (king_position.0 + move_.0, king_position.1 + move_.1);
-            let piece = self.get_piece(
-                possible_enemy_knight_position.0,
-                possible_enemy_knight_position.1,
-            );
-            match piece {
-                Some(Piece::Knight(piece_color)) => {
-                    if *piece_color == color {
-                        return true;
-                    }
-                }
-                _ => (),
-            }
-        }
-        false
+    /// Returns true if any piece of color attacks the square
+    fn is_square_attacked(&self, square: (isize, isize), color: Color) -> bool {
+        self.chessboard.is_attacked(square, color)
     }
 
-    /// Returns true if the king_position is under check by opponent pawn
-    fn is_under_check_by_pawn(&self, king_position: (isize, isize), color: Color) -> bool {
-        let mut row = king_position.0;
-        let mut file = king_position.1;
-        // check right
-        row = if color == Color::White {
-            row + 1
-        } else {
-            row - 1
-        };
-        if row < 0 || row > 7 {
-            return false;
-        }
-        file += 1;
-        let piece = if file < 8 {
-            self.get_piece(row, file)
-        } else {
-            &None
-        };
-        let under_attack_from_right = self.under_attack_from_pawn(color, piece);
-        file -= 1;
-        let piece = if file >= 0 {
-            self.get_piece(row, file)
-        } else {
-            &None
-        };
-        let under_attack_from_left = self.under_attack_from_pawn(color, piece);
-        under_attack_from_left || under_attack_from_right
-    }
-
-    /// Helper method for is_under_check_by_pawn
-    fn under_attack_from_pawn(&self, color: Color, piece: &Option<Piece>) -> bool {
-        if piece.is_some() && *piece.unwrap().get_color() == color {
-            if piece.unwrap() == Piece::Pawn(color) {
-                return true;
-            }
-        }
-        false
-    }
-
-    /// Returns true the game is in checkmate
+    /// Returns true if color is in check and has no legal move for any piece
+    /// The game is left untouched
     fn is_under_checkmate(&mut self, color: Color) -> bool {
-        if !self.is_under_check(color) {
-            return false;
-        }
-        let king_position = self.chessboard.get_king_position(color);
-        let current_state = self.chessboard.clone();
-        let current_castling_rights = self.castling_rights.clone();
-
-        for move_ in &LEGAL_KING_MOVES {
-            let destination = (king_position.0 + move_.0, king_position.1 + move_.1);
-            match self.make_a_move(king_position, destination) {
-                Ok(()) => {
-                    self.chessboard = current_state;
-                    self.castling_rights = current_castling_rights;
-                    return false;
-                }
-                _ => {}
-            }
-            for row in 0..ROWS {
-                for col in 0..COLS {
-                    let piece = self.chessboard.get_piece(row, col);
-                    if piece.is_some() && *piece.unwrap().get_color() == color {
-                        let piece = piece.unwrap();
-                        let result = match piece {
-                            Piece::Pawn(color) => self.checkmate_helper_pawn(color, row, col),
-                            Piece::Rook(color) => self.checkmate_helper_rook(color, row, col),
-                            Piece::Knight(color) => self.checkmate_helper_knight(color, row, col),
-                            Piece::Bishop(color) => self.checkmate_helper_bishop(color, row, col),
-                            Piece::Queen(color) => self.checkmate_helper_queen(color, row, col),
-                            Piece::King(_color) => true,
-                        };
-
-                        if !result {
-                            self.chessboard = current_state;
-                            self.castling_rights = current_castling_rights;
-                            return false;
-                        }
-                    }
-                }
-            }
-        }
-        true
+        self.is_under_check(color) && !self.has_legal_move(color)
     }
 
-    fn checkmate_helper_pawn(&mut self, color: Color, row: isize, col: isize) -> bool {
-        // create an array of possible
-        let multiplier = if color == Color::White { -1 } else { 1 };
-
-        for move_ in &LEGAL_PAWN_MOVES {
-            let destination = (row + move_.0 * multiplier, col + move_.1);
-            match self.make_a_move((row, col), destination) {
-                Ok(()) => {
-                    return false;
-                }
-                _ => {}
-            }
-        }
-        true
+    /// Returns true if color is not in check and has no legal move for any piece
+    /// The game is left untouched
+    fn is_under_stalemate(&mut self, color: Color) -> bool {
+        !self.is_under_check(color) && !self.has_legal_move(color)
     }
 
-    fn checkmate_helper_rook(&mut self, _color: Color, row: isize, col: isize) -> bool {
-        for i in 0..8 {
-            let destinations = [(i, col), (row, i)];
-            for destination in destinations {
-                match self.make_a_move((row, col), destination) {
-                    Ok(()) => {
-                        return false;
-                    }
-                    _ => {}
-                }
-            }
-        }
-        true
+    /// Returns true if color has at least one legal move, whoever's turn it is
+    fn has_legal_move(&mut self, color: Color) -> bool {
+        let current_turn = self.current_turn;
+        self.current_turn = match color {
+            Color::White => 0,
+            Color::Black => 1,
+        };
+        let has_legal_move = squares(self.chessboard.occupancy(color))
+            .any(|square| !self.generate_legal_moves_from(square, false).is_empty());
+        self.current_turn = current_turn;
+        has_legal_move
     }
 
-    fn checkmate_helper_knight(&mut self, _color: Color, row: isize, col: isize) -> bool {
-        for move_ in &LEGAL_KNIGHT_MOVES {
-            let destination = (row + move_.0, col + move_.1);
-            match self.make_a_move((row, col), destination) {
-                Ok(()) => {
-                    return false;
-                }
-                _ => {}
-            }
-        }
-        true
-    }
-
-    fn checkmate_helper_bishop(&mut self, _color: Color, row: isize, col: isize) -> bool {
-        for index in 0..8 {
-            let destinations = vec![
-                (row - index, col - index),
-                (row - index, col + index),
-                (row + index, col - index),
-                (row + index, col + index),
-            ];
-            for destination in destinations {
-                match self.make_a_move((row, col), destination) {
-                    Ok(()) => {
-                        return false;
-                    }
-                    _ => {}
-                }
-            }
+    /// Returns the piece at (row, col) else None
+    pub fn get_piece(&self, row: isize, file: isize) -> &Option<Piece> {
+        if (0..ROWS).contains(&row) && (0..COLS).contains(&file) {
+            return self.chessboard.get_piece(row, file);
         }
-        true
+        &None
     }
 
-    /// Returns true if (row, col) is under checkmate for color by queen
-    fn checkmate_helper_queen(&mut self, color: Color, row: isize, col: isize) -> bool {
-        self.checkmate_helper_bishop(color, row, col) || self.checkmate_helper_rook(color, row, col)
+    /// Plays a move typed in the terminal, in SAN or in UCI long algebraic notation
+    fn play_input(
+        &mut self,
+        input: &str,
+        promotion: Option<Piece>,
+    ) -> Result<MoveOutcome, MoveError> {
+        match Move::from_uci(input) {
+            Ok(move_) => self.play_move(
+                move_.source,
+                move_.destination,
+                promotion.or(move_.promotion),
+            ),
+            Err(_) => match promotion {
+                Some(piece) => self.play_san(&format!("{}={}", input, piece_letter(&piece))),
+                None => self.play_san(input),
+            },
+        }
     }
 
-    fn is_under_stalemate(&mut self, color: Color) -> bool {
-        let king_position = self.chessboard.get_king_position(color);
-        let source = king_position;
-        let mut destination = (-1, -1);
-        for move_ in LEGAL_KING_MOVES {
-            destination = (king_position.0 + move_.0, king_position.1 + move_.1);
-            if destination.0 < 8 && destination.0 >= 0 && destination.1 < 8 && destination.1 >= 0 {
-                self._move_piece(king_position, destination);
-                match self.is_under_check(color) {
-                    false => {
-                        self._move_piece(destination, king_position);
-                        return false;
-                    },
-                    true => (),
-                }
+    /// Prompts the user for a move in Standard Algebraic Notation or another command
+    fn get_command() -> Command {
+        loop {
+            let mut input = String::new();
+            stdout().flush().unwrap();
+            println!(
+                "Enter Move, e.g. e4, Nf3, O-O, e8=Q or e2e4 (or Offer <D>raw / <R>esign / <U>ndo):"
+            );
+            stdin()
+                .read_line(&mut input)
+                .expect("Oops! Something went wrong. Please restart.");
+            match input.trim() {
+                "D" => return Command::DrawOffer,
+                "R" => return Command::Resign,
+                "U" => return Command::TakeBack,
+                "" => println!("Enter a move"),
+                san => return Command::Move(String::from(san)),
             }
         }
-        self._move_piece(destination, source);
-        !self.is_under_check(color)
     }
 
-    /// Returns the piece at (row, col) else None
-    fn get_piece(&self, row: isize, file: isize) -> &Option<Piece> {
-        if row < ROWS && row >= 0 && file < COLS && file >= 0 {
-            return self.chessboard.get_piece(row, file);
+    /// Prompts the user for the piece a pawn should be promoted to
+    fn get_promotion(color: Color) -> Option<Piece> {
+        let mut piece = String::new();
+        println!("Enter Promotion (Q|R|N|B):");
+        stdin()
+            .read_line(&mut piece)
+            .expect("Oops! Something went wrong. Please restart.");
+        match piece.trim() {
+            "Q" => Some(Piece::Queen(color)),
+            "R" => Some(Piece::Rook(color)),
+            "N" => Some(Piece::Knight(color)),
+            "B" => Some(Piece::Bishop(color)),
+            _ => None,
         }
-        &None
-    }
-
-    /// Prompts the user for source and destination
-    /// Extracts the row and column from the input and returns a tuple
-    fn get_move() -> Result<((isize, isize), (isize, isize)), GameState> {
-        let source = String::new();
-        let destination = String::new();
-        stdout().flush().unwrap();
-        let source = Self::get_position("Enter Source(or Offer <D>raw / <R>esign:", source)?;
-        let destination =
-            Self::get_position("Enter Destination(or Offer <D>raw / <R>esign:", destination)?;
-        stdout().flush().unwrap();
-        Ok((source, destination))
     }
 
-    /// Prompts for input until input is valid
-    fn get_position(str: &str, mut input: String) -> Result<(isize, isize), GameState> {
-        let mut position = (-1, -1);
-        while position == (-1, -1) {
+    /// Prompts for a yes or no answer until one is given
+    fn get_confirmation(str: &str) -> bool {
+        loop {
+            let mut input = String::new();
             println!("{}", str);
             stdin()
                 .read_line(&mut input)
                 .expect("Oops! Something went wrong. Please restart.");
-            match &input.trim()[..] {
-                "D" => return Err(GameState::DrawOffer(format!("Player offered a draw"))),
-                "R" => return Err(GameState::Resignation),
-                "Y" | "y" => return Err(GameState::GameOver(format!("Draw accepted! Game over!"))),
-                "N" | "n" => return Err(GameState::DrawRejected),
-                _ => match Self::extract_position(&input) {
-                    Ok((row, file)) => {
-                        if row < ROWS && file < COLS && row >= 0 && file >= 0 {
-                            position = (row, file);
-                        }
-                    },
-                    Err(error) => {
-                        return Err(error);
-                    },
-                },
+            match input.trim() {
+                "Y" | "y" => return true,
+                "N" | "n" => return false,
+                _ => (),
             }
         }
-        Ok(position)
-    }
-
-    /// Returns the 0-indexed (row, col) extracted from the string
-    fn extract_position(str: &str) -> Result<(isize, isize), GameState> {
-        if str.trim().len() < 2 {
-            return Err(GameState::InvalidMove(format!("Specify both file and rank!")));
-        }
-        let mut chars = str.chars();
-        let file = chars.next().unwrap() as isize;
-        if file < 97 {
-            return Err(GameState::InvalidMove(format!("Invalid file")));
-        }
-        let row = chars.next().unwrap() as isize;
-        if str.trim().len() < 2 || row  - 48 > 8 || file < 97 {
-            return Err(GameState::InvalidMove(format!("Invalid rank")));
-        }
-        let file = (file - 97) as usize;
-        let row = (8 - (row - 48)) as usize;
-
-        Ok((row as isize, file as isize))
     }
 }
@@ -1,12 +1,237 @@
-pub enum GameState {
-    InvalidMove(String),
-    InvalidDestination(String),
-    InvalidSource(String),
-    KingUnderCheck(String),
-    InvalidPromotion(String),
-    GameOver(String),
-    DrawOffer(String),
-    DrawRejected,
-    Resignation,
-    OK,
+use crate::outcome::GameResult;
+use crate::piece::Piece;
+use crate::square::describe_square;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Reasons a move or a game action is rejected
+/// Squares are 0-indexed (row, file) tuples with row 0 the 8th rank
+#[derive(PartialEq, Debug, Clone)]
+pub enum MoveError {
+    OutOfBoard((isize, isize)),
+    InvalidNotation(String), // neither SAN nor UCI
+    EmptySource((isize, isize)),
+    NotYourTurn {
+        square: (isize, isize),
+        piece: Piece,
+    },
+    CaptureOwnPiece {
+        source: (isize, isize),
+        destination: (isize, isize),
+        piece: Piece,
+    },
+    // the piece doesn't move that way
+    IllegalPieceMove {
+        piece: Piece,
+        source: (isize, isize),
+        destination: (isize, isize),
+    },
+    // another piece stands between source and destination
+    Blocked {
+        piece: Piece,
+        source: (isize, isize),
+        destination: (isize, isize),
+    },
+    // the move would leave the king of the side to move in check
+    KingInCheck {
+        source: (isize, isize),
+        destination: (isize, isize),
+    },
+    CastlingThroughCheck((isize, isize)),
+    PromotionRequired {
+        source: (isize, isize),
+        destination: (isize, isize),
+    },
+    // not a pawn reaching the last rank, or not a queen, rook, bishop or knight
+    InvalidPromotion {
+        source: (isize, isize),
+        destination: (isize, isize),
+        promotion: Piece,
+    },
+    NoMatchingMove(String),
+    AmbiguousMove(String),
+    GameOver,
+    NothingToUndo,
+    NothingToRedo,
+    NoDrawToClaim,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            MoveError::OutOfBoard(square) => write!(f, "Square {:?} is out of the board", square),
+            MoveError::InvalidNotation(notation) => {
+                write!(
+                    f,
+                    "Can't read move '{}', expected e.g. e4, Nf3 or e2e4",
+                    notation
+                )
+            }
+            MoveError::EmptySource(square) => write!(f, "No piece on {}", describe_square(*square)),
+            MoveError::NotYourTurn { square, piece } => write!(
+                f,
+                "Not your turn, {:?} on {} belongs to the opponent",
+                piece,
+                describe_square(*square)
+            ),
+            MoveError::CaptureOwnPiece {
+                destination, piece, ..
+            } => write!(
+                f,
+                "Can't capture your own {:?} on {}",
+                piece,
+                describe_square(*destination)
+            ),
+            MoveError::IllegalPieceMove {
+                piece,
+                source,
+                destination,
+            } => write!(
+                f,
+                "{:?} can't move from {} to {}",
+                piece,
+                describe_square(*source),
+                describe_square(*destination)
+            ),
+            MoveError::Blocked {
+                piece,
+                source,
+                destination,
+            } => write!(
+                f,
+                "{:?} on {} is blocked on its way to {}",
+                piece,
+                describe_square(*source),
+                describe_square(*destination)
+            ),
+            MoveError::KingInCheck {
+                source,
+                destination,
+            } => write!(
+                f,
+                "{}{} leaves the king in check",
+                describe_square(*source),
+                describe_square(*destination)
+            ),
+            MoveError::CastlingThroughCheck(square) => {
+                write!(
+                    f,
+                    "Can't castle, {} is under attack",
+                    describe_square(*square)
+                )
+            }
+            MoveError::PromotionRequired { destination, .. } => write!(
+                f,
+                "Pawn reached the last rank, add the promotion piece, e.g. {}=Q",
+                describe_square(*destination)
+            ),
+            MoveError::InvalidPromotion {
+                destination,
+                promotion,
+                ..
+            } => write!(
+                f,
+                "Can't promote to {:?} on {}",
+                promotion,
+                describe_square(*destination)
+            ),
+            MoveError::NoMatchingMove(san) => write!(f, "No legal move matches '{}'", san),
+            MoveError::AmbiguousMove(san) => write!(f, "Ambiguous move '{}'", san),
+            MoveError::GameOver => write!(f, "The game has ended"),
+            MoveError::NothingToUndo => write!(f, "No move to undo"),
+            MoveError::NothingToRedo => write!(f, "No move to redo"),
+            MoveError::NoDrawToClaim => {
+                write!(f, "No draw by repetition or fifty move rule to claim")
+            }
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+/// Errors while parsing a position in Forsyth-Edwards Notation
+#[derive(PartialEq, Debug, Clone)]
+pub enum FenError {
+    WrongFieldCount(usize),
+    InvalidPlacement(String),
+    InvalidSideToMove(String),
+    InvalidCastling(String),
+    InvalidEnPassant(String),
+    InvalidHalfmoveClock(String),
+    InvalidFullmoveNumber(String),
+}
+
+impl Display for FenError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            FenError::WrongFieldCount(count) => {
+                write!(f, "FEN should have 4 or 6 fields, found {}", count)
+            }
+            FenError::InvalidPlacement(msg) => write!(f, "Invalid piece placement: {}", msg),
+            FenError::InvalidSideToMove(side) => {
+                write!(f, "Invalid side to move '{}', expected 'w' or 'b'", side)
+            }
+            FenError::InvalidCastling(rights) => {
+                write!(f, "Invalid castling availability '{}'", rights)
+            }
+            FenError::InvalidEnPassant(square) => {
+                write!(f, "Invalid en passant square '{}'", square)
+            }
+            FenError::InvalidHalfmoveClock(clock) => {
+                write!(f, "Invalid halfmove clock '{}'", clock)
+            }
+            FenError::InvalidFullmoveNumber(number) => {
+                write!(f, "Invalid fullmove number '{}'", number)
+            }
+        }
+    }
+}
+
+impl Error for FenError {}
+
+/// Errors while reading or replaying a game in Portable Game Notation
+#[derive(PartialEq, Debug, Clone)]
+pub enum PgnError {
+    InvalidTag(String),
+    InvalidMovetext(String),
+    InvalidFen(FenError),
+    // ply is 1-indexed from the first move of the movetext
+    IllegalMove {
+        ply: usize,
+        san: String,
+        reason: MoveError,
+    },
+    // the result token disagrees with how the moves end the game
+    ResultMismatch {
+        token: GameResult,
+        board: GameResult,
+    },
+}
+
+impl Display for PgnError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            PgnError::InvalidTag(line) => write!(f, "Invalid tag pair: {}", line),
+            PgnError::InvalidMovetext(msg) => write!(f, "Invalid movetext: {}", msg),
+            PgnError::InvalidFen(error) => write!(f, "Invalid FEN tag: {}", error),
+            PgnError::IllegalMove { ply, san, reason } => {
+                write!(f, "Illegal move '{}' at ply {}: {}", san, ply, reason)
+            }
+            PgnError::ResultMismatch { token, board } => write!(
+                f,
+                "The result {} does not match the game, which ends {}",
+                token, board
+            ),
+        }
+    }
+}
+
+impl Error for PgnError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PgnError::InvalidFen(error) => Some(error),
+            PgnError::IllegalMove { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,307 @@
+use crate::bitboard::{bit, color_index, index, squares, Bitboard, KING_ATTACKS};
+use crate::board::Board;
+use crate::piece::Color::{Black, White};
+use crate::piece::{Color, Piece};
+
+/// Scores a position without searching it, implement it to plug custom heuristics into a Search
+pub trait Evaluator: Send {
+    /// Returns the score of the board in centipawns from color's point of view,
+    /// positive when color is better, color being the side to move
+    fn evaluate(&self, board: &Board, color: Color) -> i32;
+}
+
+/// Counts the material only: pawn 100, knight 320, bishop 330, rook 500, queen 900
+#[derive(PartialEq, Debug, Copy, Clone, Default)]
+pub struct MaterialEvaluator;
+
+impl Evaluator for MaterialEvaluator {
+    fn evaluate(&self, board: &Board, color: Color) -> i32 {
+        let mut score = 0;
+        for row in 0..8 {
+            for file in 0..8 {
+                if let Some(piece) = board.get_piece(row, file) {
+                    match *piece.get_color() == color {
+                        true => score += piece_value(piece),
+                        false => score -= piece_value(piece),
+                    }
+                }
+            }
+        }
+        score
+    }
+}
+
+/// Returns the value of the piece in centipawns
+pub(crate) fn piece_value(piece: &Piece) -> i32 {
+    match piece {
+        Piece::King(_) => 0,
+        Piece::Queen(_) => 900,
+        Piece::Rook(_) => 500,
+        Piece::Bishop(_) => 330,
+        Piece::Knight(_) => 320,
+        Piece::Pawn(_) => 100,
+    }
+}
+
+/// Material, piece-square tables, mobility, pawn structure and king safety, each scored
+/// for the middlegame and the endgame then blended by the material left on the board
+#[derive(PartialEq, Debug, Copy, Clone, Default)]
+pub struct StandardEvaluator;
+
+// (middlegame, endgame) values by kind in the order of Piece
+const MATERIAL: [(i32, i32); 6] = [
+    (0, 0),
+    (900, 940),
+    (500, 530),
+    (330, 320),
+    (320, 300),
+    (100, 120),
+];
+// bonus per square attacked and not occupied by a friendly piece
+const MOBILITY: [(i32, i32); 6] = [(0, 0), (1, 2), (2, 4), (5, 5), (4, 4), (0, 0)];
+// weight of each kind in the game phase, the phase goes from 24 at the start to 0
+const PHASE: [i32; 6] = [0, 4, 2, 1, 1, 0];
+const MAX_PHASE: i32 = 24;
+
+const DOUBLED_PAWN: (i32, i32) = (-10, -20);
+const ISOLATED_PAWN: (i32, i32) = (-15, -10);
+// by the number of rows a passed pawn has advanced from its starting row
+const PASSED_PAWN: [(i32, i32); 6] = [(5, 10), (10, 20), (15, 35), (25, 60), (40, 100), (60, 150)];
+const PAWN_SHIELD: i32 = 10; // per friendly pawn in front of the king, middlegame only
+const OPEN_FILE_NEAR_KING: i32 = -20;
+const KING_ZONE_ATTACK: i32 = -5; // per attack on the squares around the king
+
+const FILE_A: Bitboard = 0x0101_0101_0101_0101;
+
+// piece-square tables for white, from a8 to h1 like the board, black reads them mirrored
+#[rustfmt::skip]
+const PAWN_MIDDLEGAME: [i32; 64] = [
+     0,  0,   0,   0,   0,   0,  0,  0,
+    50, 50,  50,  50,  50,  50, 50, 50,
+    10, 10,  20,  30,  30,  20, 10, 10,
+     5,  5,  10,  25,  25,  10,  5,  5,
+     0,  0,   0,  20,  20,   0,  0,  0,
+     5, -5, -10,   0,   0, -10, -5,  5,
+     5, 10,  10, -20, -20,  10, 10,  5,
+     0,  0,   0,   0,   0,   0,  0,  0,
+];
+#[rustfmt::skip]
+const PAWN_ENDGAME: [i32; 64] = [
+     0,  0,  0,  0,  0,  0,  0,  0,
+    80, 80, 80, 80, 80, 80, 80, 80,
+    50, 50, 50, 50, 50, 50, 50, 50,
+    30, 30, 30, 30, 30, 30, 30, 30,
+    20, 20, 20, 20, 20, 20, 20, 20,
+    10, 10, 10, 10, 10, 10, 10, 10,
+     0,  0,  0,  0,  0,  0,  0,  0,
+     0,  0,  0,  0,  0,  0,  0,  0,
+];
+#[rustfmt::skip]
+const KNIGHT: [i32; 64] = [
+    -50, -40, -30, -30, -30, -30, -40, -50,
+    -40, -20,   0,   0,   0,   0, -20, -40,
+    -30,   0,  10,  15,  15,  10,   0, -30,
+    -30,   5,  15,  20,  20,  15,   5, -30,
+    -30,   0,  15,  20,  20,  15,   0, -30,
+    -30,   5,  10,  15,  15,  10,   5, -30,
+    -40, -20,   0,   5,   5,   0, -20, -40,
+    -50, -40, -30, -30, -30, -30, -40, -50,
+];
+#[rustfmt::skip]
+const BISHOP: [i32; 64] = [
+    -20, -10, -10, -10, -10, -10, -10, -20,
+    -10,   0,   0,   0,   0,   0,   0, -10,
+    -10,   0,   5,  10,  10,   5,   0, -10,
+    -10,   5,   5,  10,  10,   5,   5, -10,
+    -10,   0,  10,  10,  10,  10,   0, -10,
+    -10,  10,  10,  10,  10,  10,  10, -10,
+    -10,   5,   0,   0,   0,   0,   5, -10,
+    -20, -10, -10, -10, -10, -10, -10, -20,
+];
+#[rustfmt::skip]
+const ROOK: [i32; 64] = [
+     0,  0,  0,  0,  0,  0,  0,  0,
+     5, 10, 10, 10, 10, 10, 10,  5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+     0,  0,  0,  5,  5,  0,  0,  0,
+];
+#[rustfmt::skip]
+const QUEEN: [i32; 64] = [
+    -20, -10, -10, -5, -5, -10, -10, -20,
+    -10,   0,   0,  0,  0,   0,   0, -10,
+    -10,   0,   5,  5,  5,   5,   0, -10,
+     -5,   0,   5,  5,  5,   5,   0,  -5,
+      0,   0,   5,  5,  5,   5,   0,  -5,
+    -10,   5,   5,  5,  5,   5,   0, -10,
+    -10,   0,   5,  0,  0,   0,   0, -10,
+    -20, -10, -10, -5, -5, -10, -10, -20,
+];
+#[rustfmt::skip]
+const KING_MIDDLEGAME: [i32; 64] = [
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -20, -30, -30, -40, -40, -30, -30, -20,
+    -10, -20, -20, -20, -20, -20, -20, -10,
+     20,  20,   0,   0,   0,   0,  20,  20,
+     20,  30,  10,   0,   0,  10,  30,  20,
+];
+#[rustfmt::skip]
+const KING_ENDGAME: [i32; 64] = [
+    -50, -40, -30, -20, -20, -30, -40, -50,
+    -30, -20, -10,   0,   0, -10, -20, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -30,   0,   0,   0,   0, -30, -30,
+    -50, -30, -30, -30, -30, -30, -30, -50,
+];
+
+// (middlegame, endgame) tables by kind in the order of Piece
+const TABLES: [(&[i32; 64], &[i32; 64]); 6] = [
+    (&KING_MIDDLEGAME, &KING_ENDGAME),
+    (&QUEEN, &QUEEN),
+    (&ROOK, &ROOK),
+    (&BISHOP, &BISHOP),
+    (&KNIGHT, &KNIGHT),
+    (&PAWN_MIDDLEGAME, &PAWN_ENDGAME),
+];
+
+impl Evaluator for StandardEvaluator {
+    fn evaluate(&self, board: &Board, color: Color) -> i32 {
+        // (middlegame, endgame) scores indexed by color, white first
+        let mut scores = [(0, 0); 2];
+        let mut phase = 0;
+        let mut king_zone_attacks = [0; 2];
+        let king_zones = [White, Black].map(|color| {
+            let king = board.get_king_position(color);
+            KING_ATTACKS[index(king)] | bit(king)
+        });
+        for (row, file) in squares(board.occupancy(White) | board.occupancy(Black)) {
+            let piece = board.get_piece(row, file).expect("occupied square");
+            let (side, kind) = Board::piece_index(piece);
+            // the tables are written for white, row 0 being the 8th rank
+            let square = match side {
+                0 => index((row, file)),
+                _ => index((7 - row, file)),
+            };
+            let attacks = board.attacks((row, file), piece);
+            let mobility = (attacks & !board.occupancy(*piece.get_color())).count_ones() as i32;
+            add(&mut scores[side], MATERIAL[kind]);
+            add(
+                &mut scores[side],
+                (TABLES[kind].0[square], TABLES[kind].1[square]),
+            );
+            add(
+                &mut scores[side],
+                (MOBILITY[kind].0 * mobility, MOBILITY[kind].1 * mobility),
+            );
+            if kind != 0 {
+                king_zone_attacks[1 - side] += (attacks & king_zones[1 - side]).count_ones() as i32;
+            }
+            phase += PHASE[kind];
+        }
+        for color in [White, Black] {
+            let side = color_index(color);
+            add(&mut scores[side], pawn_structure(board, color));
+            scores[side].0 +=
+                king_safety(board, color) + KING_ZONE_ATTACK * king_zone_attacks[side];
+        }
+
+        let phase = phase.min(MAX_PHASE);
+        let middlegame = scores[0].0 - scores[1].0;
+        let endgame = scores[0].1 - scores[1].1;
+        let score = (middlegame * phase + endgame * (MAX_PHASE - phase)) / MAX_PHASE;
+        match color {
+            White => score,
+            Black => -score,
+        }
+    }
+}
+
+/// Adds a (middlegame, endgame) bonus to a score
+fn add(score: &mut (i32, i32), bonus: (i32, i32)) {
+    score.0 += bonus.0;
+    score.1 += bonus.1;
+}
+
+/// Returns the squares of the rows in front of row from color's point of view
+fn rows_ahead(row: isize, color: Color) -> Bitboard {
+    match color {
+        White => (1 << (row * 8)) - 1,
+        Black => u64::MAX.checked_shl((row as u32 + 1) * 8).unwrap_or(0),
+    }
+}
+
+/// Returns the squares of the file and of the files next to it
+fn adjacent_files(file: isize) -> Bitboard {
+    let mut files = FILE_A << file;
+    if file > 0 {
+        files |= FILE_A << (file - 1);
+    }
+    if file < 7 {
+        files |= FILE_A << (file + 1);
+    }
+    files
+}
+
+/// Scores doubled, isolated and passed pawns of color
+fn pawn_structure(board: &Board, color: Color) -> (i32, i32) {
+    let pawns = board.pieces(Piece::Pawn(color));
+    let enemy_pawns = board.pieces(Piece::Pawn(color.other()));
+    let mut score = (0, 0);
+    for file in 0..8 {
+        let count = (pawns & FILE_A << file).count_ones() as i32;
+        if count > 1 {
+            add(
+                &mut score,
+                (DOUBLED_PAWN.0 * (count - 1), DOUBLED_PAWN.1 * (count - 1)),
+            );
+        }
+        if count > 0 && pawns & adjacent_files(file) & !(FILE_A << file) == 0 {
+            add(
+                &mut score,
+                (ISOLATED_PAWN.0 * count, ISOLATED_PAWN.1 * count),
+            );
+        }
+    }
+    for (row, file) in squares(pawns) {
+        if enemy_pawns & adjacent_files(file) & rows_ahead(row, color) == 0 {
+            let advanced = match color {
+                White => 6 - row,
+                Black => row - 1,
+            };
+            add(&mut score, PASSED_PAWN[advanced.clamp(0, 5) as usize]);
+        }
+    }
+    score
+}
+
+/// Scores the pawns sheltering the king of color and the open files next to it
+fn king_safety(board: &Board, color: Color) -> i32 {
+    let (row, file) = board.get_king_position(color);
+    let pawns = board.pieces(Piece::Pawn(color));
+    let forward = match color {
+        White => -1,
+        Black => 1,
+    };
+    let mut score = 0;
+    for file in (file - 1).max(0)..=(file + 1).min(7) {
+        for distance in 1..=2 {
+            let row = row + forward * distance;
+            if (0..8).contains(&row) && pawns & bit((row, file)) != 0 {
+                score += PAWN_SHIELD;
+            }
+        }
+        if pawns & FILE_A << file == 0 {
+            score += OPEN_FILE_NEAR_KING;
+        }
+    }
+    score
+}
@@ -1,8 +1,16 @@
-mod board;
+mod bitboard;
+pub mod board;
 pub mod chess;
-mod error;
-mod piece;
+pub mod error;
+pub mod evaluation;
+pub mod moves;
+pub mod outcome;
+mod pgn;
+pub mod piece;
 mod player;
+mod san;
+pub mod search;
 mod square;
+mod zobrist;
 
 use colored::*;
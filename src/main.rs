@@ -1,32 +1,56 @@
-mod board;
-mod chess;
-mod error;
-mod piece;
-mod player;
-mod square;
-use crate::chess::Chess;
-use colored::*;
+use chess::chess::Chess;
+use chess::piece::Color;
+use chess::search::MAX_LEVEL;
 use std::io::{stdout, Write};
 
 fn main() {
-    let (name1, name2) = crate::get_player_names();
-    let mut game = Chess::new(String::from(name1), String::from(name2));
+    let (white, black) = get_players();
+    let mut game = Chess::new(white.0, black.0);
+    game.set_computer(Color::White, white.1);
+    game.set_computer(Color::Black, black.1);
     game.start();
 }
 
-/// Gets the player names
-pub fn get_player_names() -> (String, String) {
-    let name1 = get_name("player-1: (white)");
-    let name2 = get_name("player-2: (black)");
-    (String::from(name1.trim()), String::from(name2.trim()))
+/// Gets the name of each player and the strength level of the computer players
+pub fn get_players() -> ((String, Option<u32>), (String, Option<u32>)) {
+    let white = get_player("player-1: (white)");
+    let black = get_player("player-2: (black)");
+    (white, black)
+}
+
+fn get_player(prompt: &str) -> (String, Option<u32>) {
+    loop {
+        match read_line(&format!("Is {} a (H)uman or a (C)omputer? ", prompt)).as_str() {
+            "H" | "h" => return (get_name(prompt), None),
+            "C" | "c" => {
+                let level = get_level();
+                return (format!("Computer (level {})", level), Some(level));
+            }
+            _ => println!("Please enter H or C."),
+        }
+    }
 }
 
 fn get_name(prompt: &str) -> String {
-    print!("Please enter name for {}: ", prompt);
-    let mut name = String::new();
+    read_line(&format!("Please enter name for {}: ", prompt))
+}
+
+fn get_level() -> u32 {
+    loop {
+        let prompt = format!("Please enter the computer's strength (1-{}): ", MAX_LEVEL);
+        match read_line(&prompt).parse() {
+            Ok(level) if (1..=MAX_LEVEL).contains(&level) => return level,
+            _ => println!("The strength goes from 1 to {}.", MAX_LEVEL),
+        }
+    }
+}
+
+fn read_line(prompt: &str) -> String {
+    print!("{}", prompt);
+    let mut line = String::new();
     stdout().flush().unwrap();
     std::io::stdin()
-        .read_line(&mut name)
+        .read_line(&mut line)
         .expect("Umm, system crashed. Please restart.");
-    name
+    String::from(line.trim())
 }
@@ -0,0 +1,84 @@
+use crate::error::MoveError;
+use crate::piece::{Color, Piece};
+use crate::square::{describe_square, parse_square};
+use std::fmt::Display;
+
+/// A move from source to destination, squares are 0-indexed (row, file) with row 0 the 8th rank
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Move {
+    pub source: (isize, isize),
+    pub destination: (isize, isize),
+    pub promotion: Option<Piece>,
+}
+
+impl Move {
+    pub fn new(
+        source: (isize, isize),
+        destination: (isize, isize),
+        promotion: Option<Piece>,
+    ) -> Self {
+        Move {
+            source,
+            destination,
+            promotion,
+        }
+    }
+
+    /// Parses a move in UCI long algebraic notation, e.g. "e2e4" or "e7e8q"
+    pub fn from_uci(uci: &str) -> Result<Self, MoveError> {
+        let uci = uci.trim();
+        let invalid = || MoveError::InvalidNotation(String::from(uci));
+        if !uci.is_ascii() || (uci.len() != 4 && uci.len() != 5) {
+            return Err(invalid());
+        }
+        let source = parse_square(&uci[0..2]).ok_or_else(invalid)?;
+        let destination = parse_square(&uci[2..4]).ok_or_else(invalid)?;
+        // the promoted piece belongs to the side whose last rank is the destination
+        let color = match destination.0 {
+            7 => Color::Black,
+            _ => Color::White,
+        };
+        let promotion = match &uci[4..] {
+            "" => None,
+            "q" => Some(Piece::Queen(color)),
+            "r" => Some(Piece::Rook(color)),
+            "b" => Some(Piece::Bishop(color)),
+            "n" => Some(Piece::Knight(color)),
+            _ => return Err(invalid()),
+        };
+        Ok(Move::new(source, destination, promotion))
+    }
+
+    /// Returns the move in UCI long algebraic notation,
+    /// a square off the board is written as its (row, file)
+    pub fn to_uci(&self) -> String {
+        let promotion = match self.promotion {
+            Some(piece) => piece.to_fen_char().to_ascii_lowercase().to_string(),
+            None => String::new(),
+        };
+        format!(
+            "{}{}{}",
+            describe_square(self.source),
+            describe_square(self.destination),
+            promotion
+        )
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.to_uci())
+    }
+}
+
+impl From<((isize, isize), (isize, isize))> for Move {
+    fn from((source, destination): ((isize, isize), (isize, isize))) -> Self {
+        Move::new(source, destination, None)
+    }
+}
+
+impl From<Move> for ((isize, isize), (isize, isize)) {
+    fn from(move_: Move) -> Self {
+        (move_.source, move_.destination)
+    }
+}
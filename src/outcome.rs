@@ -0,0 +1,154 @@
+use crate::piece::{Color, Piece};
+use std::fmt::{Display, Formatter};
+
+/// Status of the game after a move has been played
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum GameStatus {
+    Ongoing,
+    Check,
+    Checkmate,
+    Stalemate,
+    ThreefoldRepetition,  // the side to move may claim a draw
+    FiftyMoveRule,        // the side to move may claim a draw
+    FivefoldRepetition,   // the game is drawn
+    SeventyFiveMoveRule,  // the game is drawn
+    InsufficientMaterial, // the game is drawn, neither side can checkmate
+}
+
+impl GameStatus {
+    /// Returns true if the status ends the game
+    pub fn is_game_over(&self) -> bool {
+        matches!(
+            self,
+            GameStatus::Checkmate
+                | GameStatus::Stalemate
+                | GameStatus::FivefoldRepetition
+                | GameStatus::SeventyFiveMoveRule
+                | GameStatus::InsufficientMaterial
+        )
+    }
+
+    /// Returns how the game ends, or may be ended by a claim, with this status
+    pub fn termination(&self) -> Option<Termination> {
+        match self {
+            GameStatus::Ongoing | GameStatus::Check => None,
+            GameStatus::Checkmate => Some(Termination::Checkmate),
+            GameStatus::Stalemate => Some(Termination::Stalemate),
+            GameStatus::ThreefoldRepetition | GameStatus::FivefoldRepetition => {
+                Some(Termination::Repetition)
+            }
+            GameStatus::FiftyMoveRule | GameStatus::SeventyFiveMoveRule => {
+                Some(Termination::FiftyMoveRule)
+            }
+            GameStatus::InsufficientMaterial => Some(Termination::InsufficientMaterial),
+        }
+    }
+}
+
+/// Result of a finished game
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum GameResult {
+    WhiteWins,
+    BlackWins,
+    Draw,
+}
+
+impl GameResult {
+    /// Returns the result of a win for color
+    pub fn win_for(color: Color) -> Self {
+        match color {
+            Color::White => GameResult::WhiteWins,
+            Color::Black => GameResult::BlackWins,
+        }
+    }
+
+    /// Returns the color of the winner, None for a draw
+    pub fn winner(&self) -> Option<Color> {
+        match self {
+            GameResult::WhiteWins => Some(Color::White),
+            GameResult::BlackWins => Some(Color::Black),
+            GameResult::Draw => None,
+        }
+    }
+
+    /// Parses a PGN result, "1-0", "0-1" or "1/2-1/2"
+    pub fn from_pgn(result: &str) -> Option<Self> {
+        match result {
+            "1-0" => Some(GameResult::WhiteWins),
+            "0-1" => Some(GameResult::BlackWins),
+            "1/2-1/2" => Some(GameResult::Draw),
+            _ => None,
+        }
+    }
+}
+
+/// Formats the result as in PGN
+impl Display for GameResult {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            GameResult::WhiteWins => write!(f, "1-0"),
+            GameResult::BlackWins => write!(f, "0-1"),
+            GameResult::Draw => write!(f, "1/2-1/2"),
+        }
+    }
+}
+
+/// Reason a game ended
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Termination {
+    Checkmate,
+    Resignation,
+    Stalemate,
+    Agreement,
+    Repetition,    // threefold repetition claimed or fivefold repetition
+    FiftyMoveRule, // fifty move rule claimed or seventy-five move rule
+    InsufficientMaterial,
+    Timeout,
+}
+
+impl Display for Termination {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Termination::Checkmate => write!(f, "checkmate"),
+            Termination::Resignation => write!(f, "resignation"),
+            Termination::Stalemate => write!(f, "stalemate"),
+            Termination::Agreement => write!(f, "agreement"),
+            Termination::Repetition => write!(f, "repetition"),
+            Termination::FiftyMoveRule => write!(f, "the fifty move rule"),
+            Termination::InsufficientMaterial => write!(f, "insufficient material"),
+            Termination::Timeout => write!(f, "timeout"),
+        }
+    }
+}
+
+/// Result of a successfully played move
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct MoveOutcome {
+    pub status: GameStatus,
+    pub captured: Option<Piece>,
+    pub check: bool,
+    pub checkmate: bool,
+    pub stalemate: bool,
+}
+
+impl MoveOutcome {
+    /// Builds the outcome from the captured piece and the state of the side to move
+    pub fn new(captured: Option<Piece>, check: bool, checkmate: bool, stalemate: bool) -> Self {
+        let status = if checkmate {
+            GameStatus::Checkmate
+        } else if stalemate {
+            GameStatus::Stalemate
+        } else if check {
+            GameStatus::Check
+        } else {
+            GameStatus::Ongoing
+        };
+        MoveOutcome {
+            status,
+            captured,
+            check,
+            checkmate,
+            stalemate,
+        }
+    }
+}
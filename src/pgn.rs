@@ -0,0 +1,177 @@
+use crate::error::PgnError;
+use regex::Regex;
+use std::sync::LazyLock;
+
+const LINE_WIDTH: usize = 80;
+const RESULTS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+// compiled once, on the first game read
+static TAG_PAIR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^\[([A-Za-z0-9_]+)\s+"((?:[^"\\]|\\.)*)"\]$"#)
+        .expect("tag pattern should compile")
+});
+static MOVE_NUMBER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[0-9]+\.+").expect("move number pattern should compile"));
+
+/// Tag pairs, SAN moves and result token of a game read from PGN
+pub struct PgnGame {
+    pub tags: Vec<(String, String)>,
+    pub moves: Vec<String>,
+    pub result: Option<String>,
+}
+
+impl PgnGame {
+    /// Returns the value of the tag with name, if present
+    pub fn get_tag(&self, name: &str) -> Option<&String> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Returns a game in Portable Game Notation
+/// tags: tag pairs in the order they should be written
+/// first_ply: 0-indexed ply of the first move, odd if black moved first
+/// moves: SAN of every move played
+pub fn write_pgn(
+    tags: &[(&str, String)],
+    first_ply: usize,
+    moves: &[String],
+    result: &str,
+) -> String {
+    let mut pgn = String::new();
+    for (name, value) in tags {
+        pgn.push_str(&format!(
+            "[{} \"{}\"]\n",
+            name,
+            value.replace('\\', "\\\\").replace('"', "\\\"")
+        ));
+    }
+    pgn.push('\n');
+
+    let mut tokens = vec![];
+    for (index, san) in moves.iter().enumerate() {
+        let ply = first_ply + index;
+        if ply.is_multiple_of(2) {
+            tokens.push(format!("{}. {}", ply / 2 + 1, san));
+        } else if index == 0 {
+            tokens.push(format!("{}... {}", ply / 2 + 1, san));
+        } else {
+            tokens.push(san.clone());
+        }
+    }
+    tokens.push(String::from(result));
+
+    // movetext lines are kept under 80 characters
+    let mut line = String::new();
+    for token in tokens {
+        if !line.is_empty() && line.len() + token.len() + 1 >= LINE_WIDTH {
+            pgn.push_str(&line);
+            pgn.push('\n');
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&token);
+    }
+    pgn.push_str(&line);
+    pgn.push('\n');
+    pgn
+}
+
+/// Reads the first game of a PGN text
+/// Comments, NAGs and variations are skipped, only the main line is kept
+pub fn read_pgn(pgn: &str) -> Result<PgnGame, PgnError> {
+    let mut game = PgnGame {
+        tags: vec![],
+        moves: vec![],
+        result: None,
+    };
+    let mut movetext = String::new();
+    for line in pgn.lines() {
+        let line = line.trim();
+        // lines starting with % are escaped
+        if line.starts_with('%') {
+            continue;
+        }
+        if line.starts_with('[') && movetext.trim().is_empty() {
+            let captures = TAG_PAIR
+                .captures(line)
+                .ok_or_else(|| PgnError::InvalidTag(String::from(line)))?;
+            let value = captures[2].replace("\\\"", "\"").replace("\\\\", "\\");
+            game.tags.push((String::from(&captures[1]), value));
+        } else if line.starts_with('[') {
+            // the tag section of the next game
+            break;
+        } else {
+            movetext.push_str(line);
+            movetext.push('\n');
+        }
+    }
+
+    let mut tokens = vec![];
+    let mut token = String::new();
+    let mut chars = movetext.chars();
+    let mut variation_depth = 0;
+    while let Some(character) = chars.next() {
+        match character {
+            '{' => {
+                for character in chars.by_ref() {
+                    if character == '}' {
+                        break;
+                    }
+                }
+            }
+            ';' => {
+                for character in chars.by_ref() {
+                    if character == '\n' {
+                        break;
+                    }
+                }
+            }
+            '(' => variation_depth += 1,
+            ')' => {
+                if variation_depth == 0 {
+                    return Err(PgnError::InvalidMovetext(String::from(
+                        "closing a variation that was never opened",
+                    )));
+                }
+                variation_depth -= 1
+            }
+            _ if variation_depth > 0 => continue,
+            _ if character.is_whitespace() => {
+                tokens.push(token.clone());
+                token.clear();
+                continue;
+            }
+            _ => {
+                token.push(character);
+                continue;
+            }
+        }
+        // comments and variations also end a token
+        tokens.push(token.clone());
+        token.clear();
+    }
+    tokens.push(token);
+    if variation_depth > 0 {
+        return Err(PgnError::InvalidMovetext(String::from(
+            "variation is never closed",
+        )));
+    }
+
+    for token in tokens {
+        let token = MOVE_NUMBER.replace(&token, "");
+        if token.is_empty() || token.starts_with('$') {
+            continue;
+        }
+        if RESULTS.contains(&&token[..]) {
+            game.result = Some(token.into_owned());
+            break;
+        }
+        game.moves.push(token.into_owned());
+    }
+    Ok(game)
+}
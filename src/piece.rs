@@ -37,6 +37,39 @@ impl Piece {
             Piece::Pawn(color) => color,
         }
     }
+
+    /// Returns the piece for a FEN letter, uppercase letters are white pieces
+    pub fn from_fen_char(letter: char) -> Option<Piece> {
+        let color = match letter.is_ascii_uppercase() {
+            true => Color::White,
+            false => Color::Black,
+        };
+        match letter.to_ascii_lowercase() {
+            'k' => Some(Piece::King(color)),
+            'q' => Some(Piece::Queen(color)),
+            'r' => Some(Piece::Rook(color)),
+            'b' => Some(Piece::Bishop(color)),
+            'n' => Some(Piece::Knight(color)),
+            'p' => Some(Piece::Pawn(color)),
+            _ => None,
+        }
+    }
+
+    /// Returns the FEN letter of the piece, uppercase for white pieces
+    pub fn to_fen_char(&self) -> char {
+        let letter = match self {
+            Piece::King(_) => 'k',
+            Piece::Queen(_) => 'q',
+            Piece::Rook(_) => 'r',
+            Piece::Bishop(_) => 'b',
+            Piece::Knight(_) => 'n',
+            Piece::Pawn(_) => 'p',
+        };
+        match self.get_color() {
+            Color::White => letter.to_ascii_uppercase(),
+            Color::Black => letter,
+        }
+    }
 }
 
 impl Display for Piece {
@@ -1,13 +1,19 @@
 use crate::piece::Color;
 
+#[derive(Clone)]
 pub struct Player {
     name: String,
     color: Color,
+    level: Option<u32>, // strength of a computer player, None for a human
 }
 
 impl Player {
     pub fn new(name: String, color: Color) -> Self {
-        Player { name, color }
+        Player {
+            name,
+            color,
+            level: None,
+        }
     }
 
     pub fn get_name(&self) -> &String {
@@ -17,4 +23,12 @@ impl Player {
     pub fn get_color(&self) -> &Color {
         &self.color
     }
+
+    pub fn get_level(&self) -> Option<u32> {
+        self.level
+    }
+
+    pub fn set_level(&mut self, level: Option<u32>) {
+        self.level = level;
+    }
 }
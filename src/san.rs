@@ -0,0 +1,168 @@
+use crate::chess::Chess;
+use crate::error::MoveError;
+use crate::moves::Move;
+use crate::piece::{Color, Piece};
+use crate::square::{parse_square, square_name};
+use regex::Regex;
+use std::sync::LazyLock;
+
+// compiled once, on the first move read
+static SAN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^([KQRBN])?([a-h])?([1-8])?(x)?([a-h][1-8])(?:=?([QRBN]))?$")
+        .expect("SAN pattern should compile")
+});
+
+/// Returns the letter used for the piece in Standard Algebraic Notation
+/// Pawns have no letter
+pub fn piece_letter(piece: &Piece) -> &'static str {
+    match piece {
+        Piece::King(_) => "K",
+        Piece::Queen(_) => "Q",
+        Piece::Rook(_) => "R",
+        Piece::Bishop(_) => "B",
+        Piece::Knight(_) => "N",
+        Piece::Pawn(_) => "",
+    }
+}
+
+/// Returns the SAN of a move for the side to move, without the check or checkmate suffix
+/// The move should be legal, the game is left untouched
+pub(crate) fn move_to_san(
+    game: &mut Chess,
+    source: (isize, isize),
+    destination: (isize, isize),
+    promotion: Option<Piece>,
+) -> String {
+    let (Some(source_name), Some(destination_name)) =
+        (square_name(source), square_name(destination))
+    else {
+        return String::new();
+    };
+    let piece = match game.get_piece(source.0, source.1) {
+        Some(piece) => *piece,
+        None => return String::new(),
+    };
+    let capture = game.get_piece(destination.0, destination.1).is_some();
+    match piece {
+        Piece::King(_) if (destination.1 - source.1).abs() == 2 => match destination.1 > source.1 {
+            true => String::from("O-O"),
+            false => String::from("O-O-O"),
+        },
+        Piece::Pawn(_) => {
+            let mut san = String::new();
+            // a pawn changing file always captures, en passant included
+            if source.1 != destination.1 {
+                san.push_str(&source_name[..1]);
+                san.push('x');
+            }
+            san.push_str(&destination_name);
+            if let Some(promotion) = promotion {
+                san.push('=');
+                san.push_str(piece_letter(&promotion));
+            }
+            san
+        }
+        _ => format!(
+            "{}{}{}{}",
+            piece_letter(&piece),
+            disambiguation(game, piece, source, destination),
+            if capture { "x" } else { "" },
+            destination_name
+        ),
+    }
+}
+
+/// Returns the file, rank or square needed to tell the moving piece apart
+/// from identical pieces that can also reach the destination
+fn disambiguation(
+    game: &mut Chess,
+    piece: Piece,
+    source: (isize, isize),
+    destination: (isize, isize),
+) -> String {
+    let rivals: Vec<(isize, isize)> = game
+        .generate_legal_moves()
+        .iter()
+        .filter(|move_| {
+            move_.destination == destination
+                && move_.source != source
+                && *game.get_piece(move_.source.0, move_.source.1) == Some(piece)
+        })
+        .map(|move_| move_.source)
+        .collect();
+    let name = square_name(source).expect("the source is on the board");
+    if rivals.is_empty() {
+        String::new()
+    } else if rivals.iter().all(|rival| rival.1 != source.1) {
+        String::from(&name[..1])
+    } else if rivals.iter().all(|rival| rival.0 != source.0) {
+        String::from(&name[1..])
+    } else {
+        name
+    }
+}
+
+/// Returns the move described by SAN for the side to move
+/// Check, checkmate and annotation suffixes (+, #, !, ?) are ignored
+pub(crate) fn parse_san(game: &mut Chess, san: &str) -> Result<Move, MoveError> {
+    let color = game.get_current_color();
+    let home_row = match color {
+        Color::White => 7,
+        Color::Black => 0,
+    };
+    let trimmed = san.trim().trim_end_matches(['+', '#', '!', '?']);
+    match trimmed {
+        "O-O" | "0-0" => return Ok(Move::new((home_row, 4), (home_row, 6), None)),
+        "O-O-O" | "0-0-0" => return Ok(Move::new((home_row, 4), (home_row, 2), None)),
+        _ => (),
+    }
+    let captures = SAN
+        .captures(trimmed)
+        .ok_or_else(|| MoveError::InvalidNotation(String::from(san)))?;
+    let piece = match captures.get(1).map(|letter| letter.as_str()) {
+        Some("K") => Piece::King(color),
+        Some("Q") => Piece::Queen(color),
+        Some("R") => Piece::Rook(color),
+        Some("B") => Piece::Bishop(color),
+        Some("N") => Piece::Knight(color),
+        _ => Piece::Pawn(color),
+    };
+    let source_file = captures
+        .get(2)
+        .map(|file| file.as_str().as_bytes()[0] as isize - 'a' as isize);
+    let source_row = captures
+        .get(3)
+        .map(|rank| 8 - (rank.as_str().as_bytes()[0] as isize - '0' as isize));
+    // the pattern only matches valid squares
+    let destination = parse_square(&captures[5]).unwrap();
+    let promotion = match captures.get(6).map(|letter| letter.as_str()) {
+        Some("Q") => Some(Piece::Queen(color)),
+        Some("R") => Some(Piece::Rook(color)),
+        Some("B") => Some(Piece::Bishop(color)),
+        Some("N") => Some(Piece::Knight(color)),
+        _ => None,
+    };
+    let candidates: Vec<Move> = game
+        .generate_legal_moves()
+        .into_iter()
+        .filter(|move_| {
+            move_.destination == destination
+                && (move_.promotion == promotion || promotion.is_none())
+                && *game.get_piece(move_.source.0, move_.source.1) == Some(piece)
+                && source_row.is_none_or(|source_row| source_row == move_.source.0)
+                && source_file.is_none_or(|source_file| source_file == move_.source.1)
+        })
+        .collect();
+    match candidates[..] {
+        // a pawn reaching the last rank without the promotion piece matches every promotion
+        [move_, ..] if promotion.is_none() && move_.promotion.is_some() => {
+            Err(MoveError::PromotionRequired {
+                source: move_.source,
+                destination,
+            })
+        }
+        [move_] => Ok(move_),
+        [] => Err(MoveError::NoMatchingMove(String::from(san))),
+        _ => Err(MoveError::AmbiguousMove(String::from(san))),
+    }
+}
@@ -0,0 +1,440 @@
+use crate::chess::Chess;
+use crate::evaluation::{piece_value, Evaluator, StandardEvaluator};
+use crate::moves::Move;
+use crate::piece::Piece;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const MATE: i32 = 100_000;
+const INFINITY: i32 = 1_000_000;
+const MAX_DEPTH: u32 = 64;
+/// Strongest level of Limits::level
+pub const MAX_LEVEL: u32 = 8;
+// scores beyond this are mates, the distance to mate is MATE - |score| plies
+const MATE_BOUND: i32 = MATE - 1_000;
+const TABLE_ENTRIES: usize = 1 << 18;
+
+/// Limits of a search, it stops at the first one reached
+/// A search without any limit runs until it finds a mate or reaches the maximum depth
+#[derive(PartialEq, Debug, Copy, Clone, Default)]
+pub struct Limits {
+    pub depth: Option<u32>,
+    pub nodes: Option<u64>,
+    pub time: Option<Duration>,
+}
+
+impl Limits {
+    /// Searches depth plies deep
+    pub fn depth(depth: u32) -> Self {
+        Limits {
+            depth: Some(depth),
+            ..Limits::default()
+        }
+    }
+
+    /// Stops after visiting about this many positions
+    pub fn nodes(nodes: u64) -> Self {
+        Limits {
+            nodes: Some(nodes),
+            ..Limits::default()
+        }
+    }
+
+    /// Stops once time has passed
+    pub fn time(time: Duration) -> Self {
+        Limits {
+            time: Some(time),
+            ..Limits::default()
+        }
+    }
+
+    /// Limits of a computer player, from level 1, the weakest, to MAX_LEVEL:
+    /// each level searches one ply deeper and a quarter of a second longer
+    pub fn level(level: u32) -> Self {
+        let level = level.clamp(1, MAX_LEVEL);
+        Limits {
+            depth: Some(level),
+            nodes: None,
+            time: Some(Duration::from_millis(250) * level),
+        }
+    }
+}
+
+/// Best move found by a search, with the last depth completed
+#[derive(PartialEq, Debug, Clone)]
+pub struct SearchResult {
+    pub best_move: Option<Move>, // None if the side to move has no legal move
+    pub score: i32,              // centipawns from the side to move's point of view
+    pub depth: u32,
+    pub nodes: u64,
+    pub principal_variation: Vec<Move>,
+}
+
+impl SearchResult {
+    /// Returns the number of moves to the mate found, negative if the side to move gets mated
+    pub fn mate_in(&self) -> Option<i32> {
+        let plies = MATE - self.score.abs();
+        match self.score.abs() > MATE_BOUND {
+            true => Some(self.score.signum() * (plies + 1) / 2),
+            false => None,
+        }
+    }
+}
+
+// called with the result of every completed depth
+type Progress = Box<dyn FnMut(&SearchResult) + Send>;
+
+#[derive(Copy, Clone)]
+enum Bound {
+    Exact,
+    Lower, // the score is at least this, the search failed high
+    Upper, // the score is at most this, no move raised alpha
+}
+
+#[derive(Copy, Clone)]
+struct Entry {
+    hash: u64,
+    depth: u32,
+    score: i32,
+    bound: Bound,
+    best_move: Option<Move>,
+}
+
+/// Iterative deepening alpha-beta search with quiescence, move ordering
+/// and a transposition table kept from one search to the next
+pub struct Search {
+    evaluator: Box<dyn Evaluator>,
+    progress: Option<Progress>,
+    stop: Arc<AtomicBool>,
+    table: Vec<Option<Entry>>,
+    limits: Limits,
+    start: Instant,
+    nodes: u64,
+    stopped: bool,
+    path: Vec<u64>, // hashes of the positions before the one searched, for repetitions
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Search {
+    /// Creates a search scoring positions with the StandardEvaluator
+    pub fn new() -> Self {
+        Self::with_evaluator(StandardEvaluator)
+    }
+
+    /// Creates a search scoring the positions at its leaves with evaluator
+    pub fn with_evaluator(evaluator: impl Evaluator + 'static) -> Self {
+        Search {
+            evaluator: Box::new(evaluator),
+            progress: None,
+            stop: Arc::new(AtomicBool::new(false)),
+            table: vec![None; TABLE_ENTRIES],
+            limits: Limits::default(),
+            start: Instant::now(),
+            nodes: 0,
+            stopped: false,
+            path: vec![],
+        }
+    }
+
+    /// Calls progress with the result so far every time a depth is completed
+    pub fn on_progress(&mut self, progress: impl FnMut(&SearchResult) + Send + 'static) {
+        self.progress = Some(Box::new(progress));
+    }
+
+    /// Returns a flag stopping the search from another thread once set,
+    /// it stays set and stops every later search until it is cleared
+    pub fn stop_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.stop)
+    }
+
+    /// Forgets the positions searched so far, e.g. before a new game
+    pub fn clear(&mut self) {
+        self.table.iter_mut().for_each(|entry| *entry = None);
+    }
+
+    /// Searches the best move for the side to move, the game is left untouched
+    pub fn search(&mut self, game: &Chess, limits: Limits) -> SearchResult {
+        let mut game = game.clone();
+        self.limits = limits;
+        self.start = Instant::now();
+        self.nodes = 0;
+        self.stopped = false;
+        let positions = game.positions();
+        self.path = positions[..positions.len().saturating_sub(1)].to_vec();
+
+        let moves = game.generate_legal_moves();
+        let mut result = SearchResult {
+            best_move: moves.first().copied(),
+            score: 0,
+            depth: 0,
+            nodes: 0,
+            principal_variation: vec![],
+        };
+        if moves.is_empty() || game.result().is_some() {
+            return result;
+        }
+        let max_depth = limits.depth.unwrap_or(MAX_DEPTH).clamp(1, MAX_DEPTH);
+        for depth in 1..=max_depth {
+            let score = self.alpha_beta(&mut game, depth, 0, -INFINITY, INFINITY);
+            if self.stopped {
+                break;
+            }
+            result.score = score;
+            result.depth = depth;
+            result.nodes = self.nodes;
+            result.principal_variation = self.principal_variation(&mut game, depth);
+            if let Some(move_) = result.principal_variation.first() {
+                result.best_move = Some(*move_);
+            }
+            if let Some(progress) = self.progress.as_mut() {
+                progress(&result);
+            }
+            // a deeper search can't find a shorter mate
+            if score.abs() > MATE_BOUND {
+                break;
+            }
+        }
+        result.nodes = self.nodes;
+        result
+    }
+
+    /// Returns the score of the position searched depth plies deep, ply plies from the root
+    fn alpha_beta(&mut self, game: &mut Chess, depth: u32, ply: i32, alpha: i32, beta: i32) -> i32 {
+        let hash = game.hash();
+        if ply > 0 && self.path.contains(&hash) {
+            return 0;
+        }
+        self.path.push(hash);
+        let score = match depth {
+            0 => self.quiescence(game, ply, alpha, beta),
+            _ => self.negamax(game, hash, depth, ply, alpha, beta),
+        };
+        self.path.pop();
+        score
+    }
+
+    fn negamax(
+        &mut self,
+        game: &mut Chess,
+        hash: u64,
+        depth: u32,
+        ply: i32,
+        mut alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        if self.should_stop() {
+            return 0;
+        }
+        self.nodes += 1;
+        let slot = hash as usize % self.table.len();
+        let mut table_move = None;
+        if let Some(entry) = self.table[slot].filter(|entry| entry.hash == hash) {
+            table_move = entry.best_move;
+            let score = from_table(entry.score, ply);
+            if ply > 0 && entry.depth >= depth {
+                match entry.bound {
+                    Bound::Exact => return score,
+                    Bound::Lower if score >= beta => return score,
+                    Bound::Upper if score <= alpha => return score,
+                    _ => (),
+                }
+            }
+        }
+
+        let mut moves = game.generate_legal_moves();
+        if moves.is_empty() {
+            return match game.is_under_check(game.get_current_color()) {
+                true => -MATE + ply,
+                false => 0,
+            };
+        }
+        order_moves(game, &mut moves, table_move);
+        let original_alpha = alpha;
+        let mut best_score = -INFINITY;
+        let mut best_move = None;
+        for move_ in moves {
+            let snapshot = game.snapshot();
+            game.apply_move(move_);
+            let score = -self.alpha_beta(game, depth - 1, ply + 1, -beta, -alpha);
+            game.undo_applied_move(snapshot);
+            if self.stopped {
+                return 0;
+            }
+            if score > best_score {
+                best_score = score;
+                best_move = Some(move_);
+            }
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        let bound = if best_score <= original_alpha {
+            Bound::Upper
+        } else if best_score >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.table[slot] = Some(Entry {
+            hash,
+            depth,
+            score: to_table(best_score, ply),
+            bound,
+            best_move,
+        });
+        best_score
+    }
+
+    /// Searches captures and promotions until the position is quiet,
+    /// the side to move may also stand pat with the static evaluation unless in check
+    fn quiescence(&mut self, game: &mut Chess, ply: i32, mut alpha: i32, beta: i32) -> i32 {
+        if self.should_stop() {
+            return 0;
+        }
+        self.nodes += 1;
+        let check = game.is_under_check(game.get_current_color());
+        let mut moves = match check {
+            true => game.generate_legal_moves(),
+            false => game.generate_legal_captures(),
+        };
+        if check {
+            // every evasion is searched, none means checkmate
+            if moves.is_empty() {
+                return -MATE + ply;
+            }
+        } else {
+            let stand_pat = self
+                .evaluator
+                .evaluate(game.board(), game.get_current_color());
+            if stand_pat >= beta {
+                return stand_pat;
+            }
+            alpha = alpha.max(stand_pat);
+        }
+        order_moves(game, &mut moves, None);
+        let mut best_score = match check {
+            true => -INFINITY,
+            false => alpha,
+        };
+        for move_ in moves {
+            let snapshot = game.snapshot();
+            game.apply_move(move_);
+            let score = -self.quiescence(game, ply + 1, -beta, -alpha);
+            game.undo_applied_move(snapshot);
+            if self.stopped {
+                return 0;
+            }
+            if score >= beta {
+                return score;
+            }
+            alpha = alpha.max(score);
+            best_score = best_score.max(score);
+        }
+        best_score
+    }
+
+    /// Returns true once a limit is reached or the stop flag is set,
+    /// checking the clock and the flag every 1024 nodes
+    fn should_stop(&mut self) -> bool {
+        if self.limits.nodes.is_some_and(|nodes| self.nodes >= nodes)
+            || (self.nodes.is_multiple_of(1024)
+                && (self.stop.load(Ordering::Relaxed)
+                    || self
+                        .limits
+                        .time
+                        .is_some_and(|time| self.start.elapsed() >= time)))
+        {
+            self.stopped = true;
+        }
+        self.stopped
+    }
+
+    /// Follows the best moves stored in the transposition table from the root
+    fn principal_variation(&self, game: &mut Chess, depth: u32) -> Vec<Move> {
+        let mut game = game.clone();
+        let mut variation = vec![];
+        while variation.len() < depth as usize {
+            let hash = game.hash();
+            let entry = self.table[hash as usize % self.table.len()];
+            let move_ = match entry.filter(|entry| entry.hash == hash) {
+                Some(Entry {
+                    best_move: Some(move_),
+                    ..
+                }) => move_,
+                _ => break,
+            };
+            if !game.generate_legal_moves().contains(&move_) {
+                break;
+            }
+            game.apply_move(move_);
+            variation.push(move_);
+        }
+        variation
+    }
+}
+
+/// Returns the best move for the side to move within the limits, None if it has no legal move
+pub fn best_move(game: &Chess, limits: Limits) -> Option<Move> {
+    Search::new().search(game, limits).best_move
+}
+
+/// Returns the piece the move captures, en passant included
+fn captured_piece(game: &Chess, move_: &Move) -> Option<Piece> {
+    let (source, destination) = (move_.source, move_.destination);
+    match (
+        game.get_piece(source.0, source.1),
+        game.get_piece(destination.0, destination.1),
+    ) {
+        (_, Some(piece)) => Some(*piece),
+        // a pawn moving diagonally to an empty square captures en passant
+        (Some(Piece::Pawn(color)), None) if source.1 != destination.1 => {
+            Some(Piece::Pawn(color.other()))
+        }
+        _ => None,
+    }
+}
+
+/// Sorts the moves to search the most promising first: the transposition table move,
+/// captures of the most valuable piece by the least valuable one, then promotions
+fn order_moves(game: &Chess, moves: &mut [Move], table_move: Option<Move>) {
+    moves.sort_by_cached_key(|move_| {
+        if Some(*move_) == table_move {
+            return i32::MIN;
+        }
+        let promotion = move_.promotion.as_ref().map_or(0, piece_value);
+        let capture = match captured_piece(game, move_) {
+            Some(victim) => {
+                let attacker = game.get_piece(move_.source.0, move_.source.1);
+                10 * piece_value(&victim) - attacker.as_ref().map_or(0, piece_value) + 10_000
+            }
+            None => 0,
+        };
+        -(capture + promotion)
+    });
+}
+
+/// Stores mate scores as the distance from the position rather than from the root
+fn to_table(score: i32, ply: i32) -> i32 {
+    match score {
+        score if score > MATE_BOUND => score + ply,
+        score if score < -MATE_BOUND => score - ply,
+        score => score,
+    }
+}
+
+/// Reverts to_table for a position ply plies from the root
+fn from_table(score: i32, ply: i32) -> i32 {
+    match score {
+        score if score > MATE_BOUND => score - ply,
+        score if score < -MATE_BOUND => score + ply,
+        score => score,
+    }
+}
@@ -1,24 +1,28 @@
-use crate::piece::Piece;
-
-#[derive(Debug, Copy, Clone)]
-pub struct Square {
-    pub piece: Option<Piece>,
-}
-
-impl Square {
-    pub fn new(piece: Option<Piece>) -> Self {
-        Square { piece }
-    }
-
-    pub fn get_piece(&self) -> &Option<Piece> {
-        &self.piece
+/// Returns the 0-indexed (row, file) of an algebraic square such as "e4"
+/// Row 0 is the 8th rank
+pub fn parse_square(name: &str) -> Option<(isize, isize)> {
+    let mut chars = name.chars();
+    let file = chars.next()?;
+    let rank = chars.next()?;
+    if chars.next().is_some() || !('a'..='h').contains(&file) || !('1'..='8').contains(&rank) {
+        return None;
     }
+    Some((
+        8 - (rank as isize - '0' as isize),
+        file as isize - 'a' as isize,
+    ))
+}
 
-    pub fn place_piece(&mut self, piece: Piece) {
-        self.piece = Some(piece);
+/// Returns the algebraic name of a 0-indexed (row, file) square, None if it is off the board
+pub fn square_name(square: (isize, isize)) -> Option<String> {
+    if !(0..8).contains(&square.0) || !(0..8).contains(&square.1) {
+        return None;
     }
+    let file = (b'a' + square.1 as u8) as char;
+    Some(format!("{}{}", file, 8 - square.0))
+}
 
-    pub fn remove_piece(&mut self) {
-        self.piece = None;
-    }
+/// Returns the algebraic name of a square, or its (row, file) if it is off the board
+pub fn describe_square(square: (isize, isize)) -> String {
+    square_name(square).unwrap_or_else(|| format!("{:?}", square))
 }
@@ -0,0 +1,54 @@
+/// Random keys XORed together to hash a position, generated at compile time
+/// so a hash stays the same across runs and builds
+pub(crate) struct Keys {
+    pub(crate) pieces: [[[u64; 64]; 6]; 2], // by color, kind in the order of Piece, and square
+    pub(crate) black_to_move: u64,
+    pub(crate) castling_rights: [[u64; 2]; 2], // same indexes as Chess::castling_rights
+    pub(crate) en_passant: [u64; 8],           // by file
+}
+
+pub(crate) static KEYS: Keys = generate_keys();
+
+/// Returns the next state and output of the SplitMix64 generator
+const fn split_mix(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut key = state;
+    key = (key ^ (key >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    key = (key ^ (key >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (state, key ^ (key >> 31))
+}
+
+const fn generate_keys() -> Keys {
+    let mut keys = Keys {
+        pieces: [[[0; 64]; 6]; 2],
+        black_to_move: 0,
+        castling_rights: [[0; 2]; 2],
+        en_passant: [0; 8],
+    };
+    let mut state = 0x2545_f491_4f6c_dd1d;
+    let mut index = 0;
+    while index < 2 * 6 * 64 {
+        let (next, key) = split_mix(state);
+        state = next;
+        keys.pieces[index / 384][index / 64 % 6][index % 64] = key;
+        index += 1;
+    }
+    let (next, key) = split_mix(state);
+    state = next;
+    keys.black_to_move = key;
+    index = 0;
+    while index < 4 {
+        let (next, key) = split_mix(state);
+        state = next;
+        keys.castling_rights[index / 2][index % 2] = key;
+        index += 1;
+    }
+    index = 0;
+    while index < 8 {
+        let (next, key) = split_mix(state);
+        state = next;
+        keys.en_passant[index] = key;
+        index += 1;
+    }
+    keys
+}
@@ -0,0 +1,100 @@
+use chess::board::Board;
+use chess::chess::Chess;
+use chess::evaluation::{Evaluator, MaterialEvaluator, StandardEvaluator};
+use chess::piece::Color;
+use chess::search::{Limits, Search};
+
+fn evaluate(placement: &str, color: Color) -> i32 {
+    StandardEvaluator.evaluate(&Board::from_fen_placement(placement).unwrap(), color)
+}
+
+/// Returns the placement with the ranks reversed and the colors swapped
+fn mirror(placement: &str) -> String {
+    let ranks: Vec<&str> = placement.split('/').rev().collect();
+    ranks
+        .join("/")
+        .chars()
+        .map(|letter| match letter.is_uppercase() {
+            true => letter.to_ascii_lowercase(),
+            false => letter.to_ascii_uppercase(),
+        })
+        .collect()
+}
+
+#[test]
+fn starting_position_is_balanced() {
+    let game = Chess::new(String::from("white"), String::from("black"));
+    assert_eq!(StandardEvaluator.evaluate(game.board(), Color::White), 0);
+    assert_eq!(StandardEvaluator.evaluate(game.board(), Color::Black), 0);
+}
+
+#[test]
+fn scores_are_symmetric() {
+    for placement in [
+        "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8",
+    ] {
+        let score = evaluate(placement, Color::White);
+        assert_eq!(evaluate(placement, Color::Black), -score);
+        assert_eq!(evaluate(&mirror(placement), Color::Black), score);
+    }
+}
+
+#[test]
+fn material_counts_the_most() {
+    // a knight up
+    let score = evaluate("rnbqkb1r/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR", Color::White);
+    assert!(score > 200);
+    assert_eq!(
+        MaterialEvaluator.evaluate(
+            &Board::from_fen_placement("4k3/8/8/3q4/8/8/3R4/4K3").unwrap(),
+            Color::Black
+        ),
+        400
+    );
+}
+
+#[test]
+fn pawn_structure() {
+    // the d5 pawn is passed when the black pawn stands on a7 rather than e7
+    assert!(
+        evaluate("4k3/p7/8/3P4/8/8/8/4K3", Color::White)
+            > evaluate("4k3/4p3/8/3P4/8/8/8/4K3", Color::White)
+    );
+    // doubled and isolated pawns
+    assert!(
+        evaluate("4k3/8/8/8/8/4P3/3P4/4K3", Color::White)
+            > evaluate("4k3/8/8/8/8/3P4/3P4/4K3", Color::White)
+    );
+}
+
+#[test]
+fn king_placement_depends_on_the_phase() {
+    // the king shelters behind its pawns in the middlegame
+    assert!(
+        evaluate("r2qk2r/pppppppp/8/8/8/8/PPPPPPPP/R2Q1RK1", Color::White)
+            > evaluate("r2qk2r/pppppppp/8/8/8/4K3/PPPPPPPP/R2Q1R2", Color::White)
+    );
+    // and walks to the center in the endgame
+    assert!(
+        evaluate("k7/8/8/3K4/8/8/8/8", Color::White) > evaluate("k7/8/8/8/8/8/8/K7", Color::White)
+    );
+}
+
+#[test]
+fn search_uses_the_given_evaluator() {
+    // prefers to lose material
+    struct Giveaway;
+    impl Evaluator for Giveaway {
+        fn evaluate(&self, board: &Board, color: Color) -> i32 {
+            -MaterialEvaluator.evaluate(board, color)
+        }
+    }
+
+    let game = Chess::from_fen("4k3/8/8/3q4/8/8/3R4/4K3 w - - 0 1").unwrap();
+    let result = Search::with_evaluator(Giveaway).search(&game, Limits::depth(2));
+    assert_ne!(result.best_move.unwrap().to_string(), "d2d5");
+    let result = Search::with_evaluator(MaterialEvaluator).search(&game, Limits::depth(2));
+    assert_eq!(result.best_move.unwrap().to_string(), "d2d5");
+}
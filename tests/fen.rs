@@ -0,0 +1,119 @@
+use chess::chess::Chess;
+use chess::error::FenError;
+
+#[test]
+fn rejects_the_side_not_to_move_in_check() {
+    // white to move could capture the black king
+    assert!(matches!(
+        Chess::from_fen("4k3/8/8/8/8/8/8/4RK2 w - - 0 1"),
+        Err(FenError::InvalidPlacement(_))
+    ));
+    assert!(Chess::from_fen("4k3/8/8/8/8/8/8/4RK2 b - - 0 1").is_ok());
+}
+
+#[test]
+fn rejects_pawns_on_the_first_and_last_ranks() {
+    for fen in [
+        "P3k3/8/8/8/8/8/8/4K3 w - - 0 1",
+        "4k3/8/8/8/8/8/8/p3K3 w - - 0 1",
+    ] {
+        assert!(matches!(
+            Chess::from_fen(fen),
+            Err(FenError::InvalidPlacement(_))
+        ));
+    }
+}
+
+#[test]
+fn en_passant_square_needs_a_pawn_that_just_moved_two_squares() {
+    for fen in [
+        // the square is on the side to move's half of the board
+        "4k3/8/8/8/8/8/3PP3/4K3 w - e3 0 1",
+        "4k3/8/8/8/3p4/8/8/4K3 b - e3 0 1",
+        // no black pawn stands beyond e6
+        "4k3/8/8/8/8/8/8/4K3 w - e6 0 1",
+        // the pawn's starting square is occupied
+        "4k3/4p3/8/3Pp3/8/8/8/4K3 w - e6 0 1",
+    ] {
+        assert!(
+            matches!(Chess::from_fen(fen), Err(FenError::InvalidEnPassant(_))),
+            "{}",
+            fen
+        );
+    }
+    let mut game = Chess::from_fen("4k3/8/8/3Pp3/8/8/8/4K3 w - e6 0 1").unwrap();
+    game.play_uci("d5e6").unwrap();
+    assert_eq!(game.to_fen(), "4k3/8/4P3/8/8/8/8/4K3 b - - 0 1");
+}
+
+#[test]
+fn round_trip() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 12 40",
+        "r3k3/8/8/8/8/8/8/4K2R b Kq - 3 17",
+    ] {
+        assert_eq!(Chess::from_fen(fen).unwrap().to_fen(), fen);
+    }
+    let start = Chess::new(String::from("white"), String::from("black"));
+    assert_eq!(
+        start.to_fen(),
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+    );
+}
+
+#[test]
+fn clocks_may_be_omitted() {
+    let game = Chess::from_fen("4k3/8/8/8/8/8/8/4K3 b - -").unwrap();
+    assert_eq!(game.to_fen(), "4k3/8/8/8/8/8/8/4K3 b - - 0 1");
+}
+
+#[test]
+fn errors() {
+    let error = |fen: &str| Chess::from_fen(fen).err().unwrap();
+    assert_eq!(
+        error("4k3/8/8/8/8/8/8/4K3 w - - 0"),
+        FenError::WrongFieldCount(5)
+    );
+    for placement in [
+        "4k3/8/8/8/8/8/8",      // seven ranks
+        "4k3/8/8/8/8/8/8/4K2",  // a short rank
+        "4k3/8/8/8/8/8/8/4K4",  // a long rank
+        "4k3/8/8/8/8/8/8/4X3",  // an unknown piece
+        "4k3/8/8/8/8/8/8/8",    // no white king
+        "4k3/8/8/8/8/8/8/3KK3", // two white kings
+    ] {
+        assert!(
+            matches!(
+                error(&format!("{} w - - 0 1", placement)),
+                FenError::InvalidPlacement(_)
+            ),
+            "{}",
+            placement
+        );
+    }
+    assert_eq!(
+        error("4k3/8/8/8/8/8/8/4K3 x - - 0 1"),
+        FenError::InvalidSideToMove(String::from("x"))
+    );
+    for castling in ["KK", "KQx", "kqKQ1"] {
+        assert_eq!(
+            error(&format!("4k3/8/8/8/8/8/8/4K3 w {} - 0 1", castling)),
+            FenError::InvalidCastling(String::from(castling))
+        );
+    }
+    assert_eq!(
+        error("4k3/8/8/8/8/8/8/4K3 w - i9 0 1"),
+        FenError::InvalidEnPassant(String::from("i9"))
+    );
+    assert_eq!(
+        error("4k3/8/8/8/8/8/8/4K3 w - - -1 1"),
+        FenError::InvalidHalfmoveClock(String::from("-1"))
+    );
+    assert_eq!(
+        error("4k3/8/8/8/8/8/8/4K3 w - - 0 0"),
+        FenError::InvalidFullmoveNumber(String::from("0"))
+    );
+}
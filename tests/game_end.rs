@@ -0,0 +1,239 @@
+use chess::chess::Chess;
+use chess::error::MoveError;
+use chess::outcome::{GameResult, GameStatus, Termination};
+use chess::piece::Color;
+
+#[test]
+fn stalemate_when_no_piece_can_move() {
+    let mut game = Chess::from_fen("7k/8/6K1/8/8/8/8/5Q2 w - - 0 1").unwrap();
+    let outcome = game.play_uci("f1f7").unwrap();
+    assert!(outcome.stalemate);
+    assert_eq!(outcome.status, GameStatus::Stalemate);
+    assert_eq!(
+        game.result(),
+        Some((GameResult::Draw, Termination::Stalemate))
+    );
+}
+
+#[test]
+fn no_stalemate_when_a_pawn_can_move() {
+    let mut game = Chess::from_fen("7k/8/6K1/p7/8/8/8/5Q2 w - - 0 1").unwrap();
+    let outcome = game.play_uci("f1f7").unwrap();
+    assert!(!outcome.stalemate);
+    assert_eq!(outcome.status, GameStatus::Ongoing);
+}
+
+#[test]
+fn stalemate_detection_leaves_the_board_untouched() {
+    let mut game = Chess::from_fen("7k/8/6K1/p7/8/8/8/5Q2 w - - 0 1").unwrap();
+    game.play_uci("f1f7").unwrap();
+    assert_eq!(game.to_fen(), "7k/5Q2/6K1/p7/8/8/8/8 b - - 1 1");
+}
+
+#[test]
+fn fools_mate_is_checkmate() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    for san in ["f3", "e5", "g4"] {
+        assert!(!game.play_san(san).unwrap().checkmate);
+    }
+    let outcome = game.play_san("Qh4#").unwrap();
+    assert!(outcome.checkmate);
+    assert_eq!(outcome.status, GameStatus::Checkmate);
+    assert_eq!(
+        game.result(),
+        Some((GameResult::BlackWins, Termination::Checkmate))
+    );
+}
+
+#[test]
+fn no_checkmate_when_the_check_can_be_blocked() {
+    let mut game = Chess::from_fen("6k1/5ppp/8/8/8/8/1B3PPP/R5K1 w - - 0 1").unwrap();
+    let outcome = game.play_san("Ra8+").unwrap();
+    assert!(outcome.check);
+    assert!(outcome.checkmate);
+    let mut game = Chess::from_fen("6k1/1b3ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+    let outcome = game.play_san("Ra8+").unwrap();
+    assert!(!outcome.checkmate);
+}
+
+#[test]
+fn no_checkmate_when_en_passant_removes_the_checking_pawn() {
+    let mut game = Chess::from_fen("8/8/8/2k5/4p3/8/3P4/4K3 w - - 0 1").unwrap();
+    let outcome = game.play_san("d4+").unwrap();
+    assert!(outcome.check);
+    assert!(!outcome.checkmate);
+    assert_eq!(game.to_fen(), "8/8/8/2k5/3Pp3/8/8/4K3 b - d3 0 1");
+    assert!(game
+        .legal_moves()
+        .iter()
+        .any(|move_| move_.to_uci() == "e4d3"));
+}
+
+#[test]
+fn no_checkmate_when_a_promotion_captures_the_checking_piece() {
+    let mut game = Chess::from_fen("7K/6P1/7k/8/8/8/8/5r2 b - - 0 1").unwrap();
+    let outcome = game.play_san("Rf8+").unwrap();
+    assert!(outcome.check);
+    assert!(!outcome.checkmate);
+    // g8 blocks, f8 captures, each with four promotion pieces
+    assert_eq!(game.legal_moves().len(), 8);
+}
+
+#[test]
+fn threefold_repetition_can_be_claimed() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    for san in ["Nf3", "Nf6", "Ng1", "Ng8", "Nf3", "Nf6", "Ng1"] {
+        assert_eq!(game.play_san(san).unwrap().status, GameStatus::Ongoing);
+    }
+    assert!(!game.can_claim_draw());
+    let outcome = game.play_san("Ng8").unwrap();
+    assert_eq!(outcome.status, GameStatus::ThreefoldRepetition);
+    assert!(game.claim_draw().is_ok());
+    assert_eq!(
+        game.result(),
+        Some((GameResult::Draw, Termination::Repetition))
+    );
+    assert!(game.play_san("Nf3").is_err());
+}
+
+#[test]
+fn fivefold_repetition_ends_the_game() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    for _ in 0..3 {
+        for san in ["Nf3", "Nf6", "Ng1", "Ng8"] {
+            game.play_san(san).unwrap();
+        }
+    }
+    for san in ["Nf3", "Nf6", "Ng1"] {
+        game.play_san(san).unwrap();
+    }
+    let outcome = game.play_san("Ng8").unwrap();
+    assert_eq!(outcome.status, GameStatus::FivefoldRepetition);
+    assert!(game.play_san("Nf3").is_err());
+}
+
+#[test]
+fn fifty_and_seventy_five_move_rules() {
+    let mut game = Chess::from_fen("7k/8/8/8/8/8/8/R6K w - - 99 80").unwrap();
+    assert_eq!(
+        game.play_san("Ra2").unwrap().status,
+        GameStatus::FiftyMoveRule
+    );
+    let mut game = Chess::from_fen("7k/8/8/8/8/8/8/R6K w - - 149 80").unwrap();
+    assert_eq!(
+        game.play_san("Ra2").unwrap().status,
+        GameStatus::SeventyFiveMoveRule
+    );
+    assert!(game.play_san("Kg8").is_err());
+}
+
+#[test]
+fn captures_and_pawn_moves_reset_the_halfmove_clock() {
+    let mut game = Chess::from_fen("7k/p7/8/8/8/8/8/R6K b - - 99 80").unwrap();
+    assert_eq!(game.play_san("a6").unwrap().status, GameStatus::Ongoing);
+    assert_eq!(game.to_fen(), "7k/8/p7/8/8/8/8/R6K w - - 0 81");
+    assert_eq!(game.play_san("Rxa6").unwrap().status, GameStatus::Ongoing);
+    assert_eq!(game.to_fen(), "7k/8/R7/8/8/8/8/7K b - - 0 81");
+}
+
+#[test]
+fn insufficient_material_ends_the_game() {
+    // king against king after the last piece is captured
+    let mut game = Chess::from_fen("7k/8/8/8/8/8/2q5/2K5 w - - 0 1").unwrap();
+    let outcome = game.play_san("Kxc2").unwrap();
+    assert_eq!(outcome.status, GameStatus::InsufficientMaterial);
+    assert_eq!(
+        game.result(),
+        Some((GameResult::Draw, Termination::InsufficientMaterial))
+    );
+    assert!(game.play_san("Kd4").is_err());
+
+    // king and knight against king
+    let mut game = Chess::from_fen("7k/8/8/8/8/8/2r5/1NK5 w - - 0 1").unwrap();
+    let outcome = game.play_san("Kxc2").unwrap();
+    assert_eq!(outcome.status, GameStatus::InsufficientMaterial);
+
+    // bishops on the same colored squares
+    let mut game = Chess::from_fen("7k/8/8/8/8/4b3/2r5/2K1B3 w - - 0 1").unwrap();
+    let outcome = game.play_san("Kxc2").unwrap();
+    assert_eq!(outcome.status, GameStatus::InsufficientMaterial);
+}
+
+#[test]
+fn mating_material_keeps_the_game_going() {
+    // bishops on opposite colored squares
+    let mut game = Chess::from_fen("7k/8/8/8/8/4b3/2r5/1BK5 w - - 0 1").unwrap();
+    assert_eq!(game.play_san("Kxc2").unwrap().status, GameStatus::Ongoing);
+
+    // two knights
+    let mut game = Chess::from_fen("7k/8/8/8/8/8/2r5/NNK5 w - - 0 1").unwrap();
+    assert_eq!(game.play_san("Kxc2").unwrap().status, GameStatus::Ongoing);
+
+    // knight and bishop
+    let mut game = Chess::from_fen("7k/8/8/8/8/8/2r5/NBK5 w - - 0 1").unwrap();
+    assert_eq!(game.play_san("Kxc2").unwrap().status, GameStatus::Ongoing);
+}
+
+#[test]
+fn results_without_a_move() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    assert_eq!(game.result(), None);
+    game.resign(Color::White).unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::BlackWins, Termination::Resignation))
+    );
+
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    game.agree_draw().unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::Draw, Termination::Agreement))
+    );
+
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    game.play_san("e4").unwrap();
+    game.timeout(Color::Black).unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::WhiteWins, Termination::Timeout))
+    );
+    assert!(game.to_pgn().ends_with("1. e4 1-0\n"));
+}
+
+#[test]
+fn a_finished_game_keeps_its_result() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    for san in ["f3", "e5", "g4", "Qh4#"] {
+        game.play_san(san).unwrap();
+    }
+    assert_eq!(game.resign(Color::Black), Err(MoveError::GameOver));
+    assert_eq!(game.agree_draw(), Err(MoveError::GameOver));
+    assert_eq!(game.timeout(Color::Black), Err(MoveError::GameOver));
+    assert_eq!(
+        game.result(),
+        Some((GameResult::BlackWins, Termination::Checkmate))
+    );
+}
+
+#[test]
+fn positions_loaded_already_over_have_a_result() {
+    let game = Chess::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::Draw, Termination::Stalemate))
+    );
+    let mut game = Chess::from_fen("7k/6Q1/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::WhiteWins, Termination::Checkmate))
+    );
+    assert_eq!(game.play_uci("h8g7"), Err(MoveError::GameOver));
+    let game = Chess::from_fen("7k/8/8/8/8/8/8/1N5K w - - 0 1").unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::Draw, Termination::InsufficientMaterial))
+    );
+    let game = Chess::from_fen("7k/8/6K1/8/8/8/8/5Q2 w - - 0 1").unwrap();
+    assert_eq!(game.result(), None);
+}
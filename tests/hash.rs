@@ -0,0 +1,78 @@
+use chess::chess::Chess;
+
+fn play(fen: &str, moves: &[&str]) -> Chess {
+    let mut game = Chess::from_fen(fen).unwrap();
+    for san in moves {
+        game.play_san(san).unwrap();
+    }
+    game
+}
+
+const START: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+#[test]
+fn transpositions_have_the_same_hash() {
+    let game = play(START, &["Nf3", "Nf6", "Nc3"]);
+    let transposed = play(START, &["Nc3", "Nf6", "Nf3"]);
+    assert_eq!(game.hash(), transposed.hash());
+    assert_ne!(game.hash(), play(START, &["Nf3", "Nf6"]).hash());
+}
+
+#[test]
+fn incremental_hash_matches_the_hash_of_the_fen() {
+    for moves in [
+        &["e4", "d5", "exd5", "Qxd5", "Nc3", "Qa5", "d4", "c6"][..],
+        &["e4", "Nf6", "e5", "d5", "exd6"][..],
+        &[
+            "g4", "h5", "gxh5", "Rxh5", "Nf3", "Rh3", "Bg2", "Rxf3", "O-O",
+        ][..],
+    ] {
+        let game = play(START, moves);
+        assert_eq!(game.hash(), Chess::from_fen(&game.to_fen()).unwrap().hash());
+    }
+    let game = play("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", &["b8=N"]);
+    assert_eq!(game.hash(), Chess::from_fen(&game.to_fen()).unwrap().hash());
+}
+
+#[test]
+fn side_to_move_castling_and_en_passant_change_the_hash() {
+    let hash = |fen: &str| Chess::from_fen(fen).unwrap().hash();
+    let position = "r3k2r/8/8/8/3pP3/8/8/R3K2R b KQkq e3 0 1";
+    // white to move can't have an en passant square on e3
+    assert_ne!(
+        hash(position),
+        hash("r3k2r/8/8/8/3pP3/8/8/R3K2R w KQkq - 0 1")
+    );
+    assert_ne!(
+        hash(position),
+        hash("r3k2r/8/8/8/3pP3/8/8/R3K2R b KQk e3 0 1")
+    );
+    assert_ne!(
+        hash(position),
+        hash("r3k2r/8/8/8/3pP3/8/8/R3K2R b KQkq - 0 1")
+    );
+    // the d4 pawn is pinned, capturing on e3 would leave the king in check
+    assert_eq!(
+        hash("3k4/8/8/8/3pP3/8/8/3RK3 b - e3 0 1"),
+        hash("3k4/8/8/8/3pP3/8/8/3RK3 b - - 0 1")
+    );
+    assert_ne!(
+        hash("3k4/8/8/8/3pP3/8/8/2R1K3 b - e3 0 1"),
+        hash("3k4/8/8/8/3pP3/8/8/2R1K3 b - - 0 1")
+    );
+    // no black pawn can capture on e3, the square doesn't change the position
+    assert_eq!(
+        hash("r3k2r/8/8/8/p3P3/8/8/R3K2R b KQkq e3 0 1"),
+        hash("r3k2r/8/8/8/p3P3/8/8/R3K2R b KQkq - 0 1")
+    );
+}
+
+#[test]
+fn undo_restores_the_hash() {
+    let mut game = play(START, &["e4", "d5"]);
+    let hash = game.hash();
+    game.play_san("exd5").unwrap();
+    assert_ne!(game.hash(), hash);
+    game.undo().unwrap();
+    assert_eq!(game.hash(), hash);
+}
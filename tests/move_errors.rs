@@ -0,0 +1,135 @@
+use chess::chess::Chess;
+use chess::error::MoveError;
+use chess::piece::{Color, Piece};
+
+fn new_game() -> Chess {
+    Chess::new(String::from("white"), String::from("black"))
+}
+
+#[test]
+fn errors_name_the_squares_and_piece() {
+    let mut game = new_game();
+    assert_eq!(game.play_uci("e3e4"), Err(MoveError::EmptySource((5, 4))));
+    assert_eq!(
+        game.play_uci("e7e5"),
+        Err(MoveError::NotYourTurn {
+            square: (1, 4),
+            piece: Piece::Pawn(Color::Black),
+        })
+    );
+    assert_eq!(
+        game.play_uci("a1b1"),
+        Err(MoveError::CaptureOwnPiece {
+            source: (7, 0),
+            destination: (7, 1),
+            piece: Piece::Knight(Color::White),
+        })
+    );
+    assert_eq!(
+        game.play_uci("g1g3"),
+        Err(MoveError::IllegalPieceMove {
+            piece: Piece::Knight(Color::White),
+            source: (7, 6),
+            destination: (5, 6),
+        })
+    );
+    assert_eq!(
+        game.play_uci("f1c4"),
+        Err(MoveError::Blocked {
+            piece: Piece::Bishop(Color::White),
+            source: (7, 5),
+            destination: (4, 2),
+        })
+    );
+    assert_eq!(
+        game.play_move((6, 4), (8, 4), None),
+        Err(MoveError::OutOfBoard((8, 4)))
+    );
+    assert_eq!(
+        game.play_move((6, 4), (5, -1), None),
+        Err(MoveError::OutOfBoard((5, -1)))
+    );
+    assert_eq!(
+        game.play_move((6, -1), (5, 0), None),
+        Err(MoveError::OutOfBoard((6, -1)))
+    );
+}
+
+#[test]
+fn errors_for_check_castling_and_promotion() {
+    let mut game = Chess::from_fen("r3k3/1P6/8/8/8/8/4r3/R3K2R w KQq - 0 1").unwrap();
+    assert_eq!(
+        game.play_uci("a1a2"),
+        Err(MoveError::KingInCheck {
+            source: (7, 0),
+            destination: (6, 0),
+        })
+    );
+    assert_eq!(
+        game.play_san("O-O"),
+        Err(MoveError::CastlingThroughCheck((7, 4)))
+    );
+    let mut game = Chess::from_fen("r3k3/1P6/8/8/8/8/8/R3K2R w KQq - 0 1").unwrap();
+    assert_eq!(
+        game.play_san("bxa8"),
+        Err(MoveError::PromotionRequired {
+            source: (1, 1),
+            destination: (0, 0),
+        })
+    );
+    assert_eq!(
+        game.play_move((1, 1), (0, 1), Some(Piece::King(Color::White))),
+        Err(MoveError::InvalidPromotion {
+            source: (1, 1),
+            destination: (0, 1),
+            promotion: Piece::King(Color::White),
+        })
+    );
+    assert_eq!(
+        game.play_move((7, 0), (6, 0), Some(Piece::Queen(Color::White))),
+        Err(MoveError::InvalidPromotion {
+            source: (7, 0),
+            destination: (6, 0),
+            promotion: Piece::Queen(Color::White),
+        })
+    );
+}
+
+#[test]
+fn errors_for_notation() {
+    let mut game = new_game();
+    assert_eq!(
+        game.play_san("Xz9"),
+        Err(MoveError::InvalidNotation(String::from("Xz9")))
+    );
+    assert_eq!(
+        game.play_uci("e2e9"),
+        Err(MoveError::InvalidNotation(String::from("e2e9")))
+    );
+    assert_eq!(
+        game.play_san("Nd2"),
+        Err(MoveError::NoMatchingMove(String::from("Nd2")))
+    );
+    let mut game = Chess::from_fen("4k3/8/8/8/8/8/4K3/R6R w - - 0 1").unwrap();
+    assert_eq!(
+        game.play_san("Rd1"),
+        Err(MoveError::AmbiguousMove(String::from("Rd1")))
+    );
+    assert_eq!(game.claim_draw(), Err(MoveError::NoDrawToClaim));
+}
+
+#[test]
+fn errors_work_with_the_question_mark_operator() {
+    fn play() -> Result<(), Box<dyn std::error::Error>> {
+        // a rook keeps the game from being a dead draw
+        let mut game = Chess::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1")?;
+        game.play_san("Ke2")?;
+        game.play_san("Ke9")?;
+        Ok(())
+    }
+    let error = play().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Can't read move 'Ke9', expected e.g. e4, Nf3 or e2e4"
+    );
+}
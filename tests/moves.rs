@@ -0,0 +1,41 @@
+use chess::error::MoveError;
+use chess::moves::Move;
+use chess::piece::{Color, Piece};
+
+#[test]
+fn parses_uci() {
+    assert_eq!(Move::from_uci("e2e4"), Ok(Move::new((6, 4), (4, 4), None)));
+    assert_eq!(
+        Move::from_uci("e7e8q"),
+        Ok(Move::new((1, 4), (0, 4), Some(Piece::Queen(Color::White))))
+    );
+    assert_eq!(
+        Move::from_uci("b2a1n"),
+        Ok(Move::new((6, 1), (7, 0), Some(Piece::Knight(Color::Black))))
+    );
+}
+
+#[test]
+fn rejects_invalid_uci() {
+    for uci in [
+        "i9", "e2", "e2e9", "i2e4", "e2e4x", "a7a8Q", "a7a8k", "e2e4e5", "é2e4",
+    ] {
+        assert_eq!(
+            Move::from_uci(uci),
+            Err(MoveError::InvalidNotation(String::from(uci))),
+            "{}",
+            uci
+        );
+    }
+}
+
+#[test]
+fn formats_uci() {
+    for uci in ["e2e4", "e1g1", "a7a8q", "h2h1r", "c7c8b"] {
+        let move_ = Move::from_uci(uci).unwrap();
+        assert_eq!(move_.to_uci(), uci);
+        assert_eq!(move_.to_string(), uci);
+    }
+    let move_: Move = ((6, 4), (4, 4)).into();
+    assert_eq!(move_.to_string(), "e2e4");
+}
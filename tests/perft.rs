@@ -0,0 +1,91 @@
+use chess::chess::Chess;
+
+// Node counts from https://www.chessprogramming.org/Perft_Results
+
+const START: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+const POSITION_3: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+const POSITION_4: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+const POSITION_4_MIRRORED: &str =
+    "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1";
+const POSITION_5: &str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+const POSITION_6: &str = "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+
+fn assert_perft(fen: &str, counts: &[u64]) {
+    let game = Chess::from_fen(fen).unwrap();
+    for (depth, count) in counts.iter().enumerate() {
+        assert_eq!(
+            game.perft(depth + 1),
+            *count,
+            "{} at depth {}",
+            fen,
+            depth + 1
+        );
+    }
+}
+
+#[test]
+fn perft_start_position() {
+    assert_perft(START, &[20, 400, 8902, 197281]);
+}
+
+#[test]
+fn perft_kiwipete() {
+    assert_perft(KIWIPETE, &[48, 2039, 97862]);
+}
+
+#[test]
+fn perft_position_3() {
+    assert_perft(POSITION_3, &[14, 191, 2812, 43238]);
+}
+
+#[test]
+fn perft_position_4() {
+    assert_perft(POSITION_4, &[6, 264, 9467]);
+}
+
+#[test]
+fn perft_position_4_mirrored() {
+    assert_perft(POSITION_4_MIRRORED, &[6, 264, 9467]);
+}
+
+#[test]
+fn perft_position_5() {
+    assert_perft(POSITION_5, &[44, 1486, 62379]);
+}
+
+#[test]
+fn perft_position_6() {
+    assert_perft(POSITION_6, &[46, 2079, 89890]);
+}
+
+#[test]
+fn perft_new_game_matches_start_position() {
+    let game = Chess::new(String::from("white"), String::from("black"));
+    assert_eq!(game.perft(2), 400);
+}
+
+#[test]
+fn divide_sums_to_perft() {
+    let game = Chess::from_fen(KIWIPETE).unwrap();
+    let divide = game.divide(2);
+    assert_eq!(divide.len(), 48);
+    assert_eq!(divide.iter().map(|(_, count)| count).sum::<u64>(), 2039);
+}
+
+#[test]
+fn perft_depth_zero_is_one_node() {
+    assert_eq!(Chess::from_fen(START).unwrap().perft(0), 1);
+}
+
+// Slow in debug builds, run with `cargo test --release -- --ignored`
+#[test]
+#[ignore]
+fn perft_deep() {
+    let start = Chess::from_fen(START).unwrap();
+    assert_eq!(start.perft(5), 4865609);
+    let kiwipete = Chess::from_fen(KIWIPETE).unwrap();
+    assert_eq!(kiwipete.perft(4), 4085603);
+    let position_3 = Chess::from_fen(POSITION_3).unwrap();
+    assert_eq!(position_3.perft(5), 674624);
+}
@@ -0,0 +1,186 @@
+use chess::chess::Chess;
+use chess::error::{FenError, MoveError, PgnError};
+use chess::outcome::{GameResult, Termination};
+use chess::piece::Color;
+
+const OPERA_GAME: [&str; 33] = [
+    "e4", "e5", "Nf3", "d6", "d4", "Bg4", "dxe5", "Bxf3", "Qxf3", "dxe5", "Bc4", "Nf6", "Qb3",
+    "Qe7", "Nc3", "c6", "Bg5", "b5", "Nxb5", "cxb5", "Bxb5+", "Nbd7", "O-O-O", "Rd8", "Rxd7",
+    "Rxd7", "Rd1", "Qe6", "Bxd7+", "Nxd7", "Qb8+", "Nxb8", "Rd8#",
+];
+
+#[test]
+fn reads_tags_with_escaped_values() {
+    let pgn = "[Event \"The \\\"Opera\\\" game\"]\n[Site \"Paris \\\\ France\"]\n\n1. e4 *\n";
+    let game = Chess::from_pgn(pgn).unwrap();
+    let exported = game.to_pgn();
+    assert!(exported.contains("[Event \"The \\\"Opera\\\" game\"]\n"));
+    assert!(exported.contains("[Site \"Paris \\\\ France\"]\n"));
+    assert_eq!(
+        Chess::from_pgn("[Event \"unterminated]\n\n1. e4 *").err(),
+        Some(PgnError::InvalidTag(String::from("[Event \"unterminated]")))
+    );
+}
+
+#[test]
+fn skips_comments_nags_variations_and_escaped_lines() {
+    let pgn = "[Event \"?\"]\n\
+        % an escaped line 1. h4\n\
+        \n\
+        1. e4 {the king's pawn,\n\
+        over two lines (not a variation)} e5 $1 2.Nf3 ; a comment to the end of the line 2. h4\n\
+        Nc6 (2... d6 3. d4 (3. Bc4 {nested} Be7) exd4) 3. Bb5 $14 a6!? *\n";
+    let game = Chess::from_pgn(pgn).unwrap();
+    assert_eq!(game.get_moves(), &["e4", "e5", "Nf3", "Nc6", "Bb5", "a6"]);
+    assert_eq!(game.result(), None);
+}
+
+#[test]
+fn reads_the_first_game_only() {
+    let pgn = "[Event \"first\"]\n\n1. e4 e5 1/2-1/2\n\n[Event \"second\"]\n\n1. d4 d5 *\n";
+    let game = Chess::from_pgn(pgn).unwrap();
+    assert_eq!(game.get_moves(), &["e4", "e5"]);
+    assert_eq!(
+        game.result(),
+        Some((GameResult::Draw, Termination::Agreement))
+    );
+}
+
+#[test]
+fn rejects_unbalanced_variations() {
+    for pgn in ["1. e4 (1. d4 e5", "1. e4 1... d4) e5", "1. e4 ((1. d4) e5"] {
+        assert!(
+            matches!(Chess::from_pgn(pgn), Err(PgnError::InvalidMovetext(_))),
+            "{}",
+            pgn
+        );
+    }
+}
+
+#[test]
+fn reports_the_first_illegal_move_with_its_ply() {
+    assert_eq!(
+        Chess::from_pgn("1. e4 e5 2. Ke3 Nc6 *").err(),
+        Some(PgnError::IllegalMove {
+            ply: 3,
+            san: String::from("Ke3"),
+            reason: MoveError::NoMatchingMove(String::from("Ke3")),
+        })
+    );
+    assert_eq!(
+        Chess::from_pgn("1. e4 e5 2. Nc3 Nc6 3. Ne2 *").err(),
+        Some(PgnError::IllegalMove {
+            ply: 5,
+            san: String::from("Ne2"),
+            reason: MoveError::AmbiguousMove(String::from("Ne2")),
+        })
+    );
+    assert!(matches!(
+        Chess::from_pgn("1. e4 e5 2. Xz9"),
+        Err(PgnError::IllegalMove {
+            ply: 3,
+            reason: MoveError::InvalidNotation(_),
+            ..
+        })
+    ));
+}
+
+#[test]
+fn starts_from_the_fen_tag() {
+    let pgn = "[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4P3/4K3 b - - 0 12\"]\n\n12... Kd7 13. e4 *\n";
+    let game = Chess::from_pgn(pgn).unwrap();
+    assert_eq!(game.to_fen(), "8/3k4/8/8/4P3/8/8/4K3 b - e3 0 13");
+    assert_eq!(
+        Chess::from_pgn("[FEN \"4k3/8/8/8 w - - 0 1\"]\n\n*").err(),
+        Some(PgnError::InvalidFen(FenError::InvalidPlacement(
+            String::from("expected 8 ranks, found 4")
+        )))
+    );
+}
+
+#[test]
+fn keeps_the_result_of_games_not_ended_on_the_board() {
+    let game = Chess::from_pgn("1. e4 e5 0-1").unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::BlackWins, Termination::Resignation))
+    );
+    let game = Chess::from_pgn("[Termination \"Time forfeit\"]\n\n1. e4 1-0").unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::WhiteWins, Termination::Timeout))
+    );
+}
+
+#[test]
+fn draws_claimed_on_the_board_keep_their_reason() {
+    let game = Chess::from_pgn("1. Nf3 Nf6 2. Ng1 Ng8 3. Nf3 Nf6 4. Ng1 Ng8 1/2-1/2").unwrap();
+    assert_eq!(
+        game.result(),
+        Some((GameResult::Draw, Termination::Repetition))
+    );
+}
+
+#[test]
+fn rejects_a_result_the_moves_contradict() {
+    let error = Chess::from_pgn("1. f3 e5 2. g4 Qh4# 1-0").err().unwrap();
+    assert_eq!(
+        error,
+        PgnError::ResultMismatch {
+            token: GameResult::WhiteWins,
+            board: GameResult::BlackWins,
+        }
+    );
+    assert!(Chess::from_pgn("1. f3 e5 2. g4 Qh4# 0-1").is_ok());
+}
+
+#[test]
+fn export_and_import_round_trip() {
+    let mut game = Chess::new(String::from("Morphy"), String::from("Allies"));
+    for san in OPERA_GAME {
+        game.play_san(san).unwrap();
+    }
+    let pgn = game.to_pgn();
+    assert!(pgn.contains("[White \"Morphy\"]\n[Black \"Allies\"]\n[Result \"1-0\"]\n"));
+    assert!(pgn.starts_with("[Event "));
+    let movetext: Vec<&str> = pgn.split("\n\n").nth(1).unwrap().lines().collect();
+    assert!(movetext.len() > 1);
+    assert!(movetext.iter().all(|line| line.len() < 80));
+    assert!(movetext[0].starts_with("1. e4 e5 2. Nf3 d6"));
+    assert!(movetext.last().unwrap().ends_with("17. Rd8# 1-0"));
+
+    let imported = Chess::from_pgn(&pgn).unwrap();
+    assert_eq!(imported.get_moves(), game.get_moves());
+    assert_eq!(imported.to_fen(), game.to_fen());
+    assert_eq!(
+        imported.result(),
+        Some((GameResult::WhiteWins, Termination::Checkmate))
+    );
+    assert_eq!(imported.to_pgn(), pgn);
+}
+
+#[test]
+fn black_moving_first_is_numbered_with_an_ellipsis() {
+    let mut game = Chess::from_fen("4k3/8/8/8/8/8/4P3/4K3 b - - 0 12").unwrap();
+    game.play_san("Kd7").unwrap();
+    game.play_san("e4").unwrap();
+    game.resign(Color::Black).unwrap();
+    let pgn = game.to_pgn();
+    assert!(pgn.contains("[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4P3/4K3 b - - 0 12\"]\n"));
+    assert!(pgn.ends_with("\n\n12... Kd7 13. e4 1-0\n"));
+    let imported = Chess::from_pgn(&pgn).unwrap();
+    assert_eq!(imported.to_fen(), game.to_fen());
+    assert_eq!(imported.to_pgn(), pgn);
+}
+
+#[test]
+fn fen_tag_has_six_fields() {
+    let game = Chess::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - -").unwrap();
+    assert!(game
+        .to_pgn()
+        .contains("[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4P3/4K3 w - - 0 1\"]\n"));
+    // the standard starting position needs no tag
+    let game = Chess::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -").unwrap();
+    assert!(!game.to_pgn().contains("FEN"));
+    assert!(!game.to_pgn().contains("SetUp"));
+}
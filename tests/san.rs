@@ -0,0 +1,58 @@
+use chess::chess::Chess;
+use chess::error::MoveError;
+use chess::piece::{Color, Piece};
+
+#[test]
+fn disambiguates_by_file_then_rank_then_square() {
+    // both rooks reach d1
+    let mut game = Chess::from_fen("4k3/8/8/8/8/8/8/R4RK1 w - - 0 1").unwrap();
+    assert_eq!(game.to_san((7, 0), (7, 3), None), "Rad1");
+    assert_eq!(game.to_san((7, 5), (7, 3), None), "Rfd1");
+
+    // both rooks are on the a file
+    let mut game = Chess::from_fen("4k3/8/8/8/8/R7/8/R5K1 w - - 0 1").unwrap();
+    assert_eq!(game.to_san((7, 0), (6, 0), None), "R1a2");
+    assert_eq!(game.to_san((5, 0), (6, 0), None), "R3a2");
+
+    // the h4 queen shares its rank with e4 and its file with h1, the h1 queen only its file
+    let mut game = Chess::from_fen("2k5/8/8/8/4Q2Q/8/8/K6Q w - - 0 1").unwrap();
+    assert_eq!(game.to_san((4, 7), (7, 4), None), "Qh4e1");
+    assert_eq!(game.to_san((4, 4), (7, 4), None), "Qee1");
+    assert_eq!(game.to_san((7, 7), (7, 4), None), "Q1e1");
+    assert!(matches!(
+        game.play_san("Qe1"),
+        Err(MoveError::AmbiguousMove(_))
+    ));
+    game.play_san("Qh4e1").unwrap();
+    assert_eq!(game.get_moves().last().unwrap(), "Qh4e1");
+}
+
+#[test]
+fn pawn_moves_castling_and_suffixes() {
+    let mut game = Chess::from_fen("r3k3/1P6/8/3pP3/8/8/8/R3K2R w KQq d6 0 1").unwrap();
+    assert_eq!(game.to_san((3, 4), (2, 3), None), "exd6");
+    assert_eq!(game.to_san((7, 4), (7, 6), None), "O-O");
+    assert_eq!(game.to_san((7, 4), (7, 2), None), "O-O-O");
+    let queen = Some(Piece::Queen(Color::White));
+    assert_eq!(game.to_san((1, 1), (0, 0), queen), "bxa8=Q+");
+    assert_eq!(game.to_san((7, 0), (0, 0), None), "Rxa8+");
+}
+
+#[test]
+fn parses_san() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    for san in ["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "Bxc6", "dxc6", "O-O"] {
+        game.play_san(san).unwrap();
+    }
+    // suffixes are ignored
+    game.play_san("Bg4!?").unwrap();
+    game.play_san("h3").unwrap();
+    assert_eq!(
+        game.play_san("Zz9"),
+        Err(MoveError::InvalidNotation(String::from("Zz9")))
+    );
+    assert_eq!(
+        game.play_san("Nc6"),
+        Err(MoveError::NoMatchingMove(String::from("Nc6")))
+    );
+}
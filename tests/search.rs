@@ -0,0 +1,65 @@
+use chess::chess::Chess;
+use chess::search::{best_move, Limits, Search, MAX_LEVEL};
+use std::time::{Duration, Instant};
+
+#[test]
+fn finds_mate_in_one() {
+    let game = Chess::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+    let result = Search::new().search(&game, Limits::depth(3));
+    assert_eq!(result.best_move.unwrap().to_string(), "a1a8");
+    assert_eq!(result.mate_in(), Some(1));
+}
+
+#[test]
+fn finds_mate_in_two() {
+    // Ra7 or Rb7 keeps the king on the back rank, the other rook mates next
+    let game = Chess::from_fen("6k1/8/8/8/8/8/R7/1R4K1 w - - 0 1").unwrap();
+    let result = Search::new().search(&game, Limits::depth(4));
+    assert_eq!(result.mate_in(), Some(2));
+    assert!(["a2a7", "b1b7"].contains(&result.best_move.unwrap().to_string().as_str()));
+    assert_eq!(result.principal_variation.len(), 3);
+}
+
+#[test]
+fn takes_a_hanging_queen() {
+    let game = Chess::from_fen("4k3/8/8/3q4/8/8/3R4/4K3 w - - 0 1").unwrap();
+    assert_eq!(
+        best_move(&game, Limits::depth(2)).unwrap().to_string(),
+        "d2d5"
+    );
+}
+
+#[test]
+fn avoids_a_defended_pawn() {
+    // Qxd5 is answered by exd5
+    let game = Chess::from_fen("4k3/8/4p3/3p4/8/8/8/3QK3 w - - 0 1").unwrap();
+    let result = Search::new().search(&game, Limits::depth(2));
+    assert_ne!(result.best_move.unwrap().to_string(), "d1d5");
+}
+
+#[test]
+fn no_move_without_legal_moves() {
+    let game = Chess::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+    assert_eq!(best_move(&game, Limits::depth(3)), None);
+}
+
+#[test]
+fn search_respects_node_and_time_limits() {
+    let game = Chess::new(String::from("white"), String::from("black"));
+    let result = Search::new().search(&game, Limits::nodes(2000));
+    assert!(result.best_move.is_some());
+    assert!(result.nodes <= 2000);
+
+    let start = Instant::now();
+    let result = Search::new().search(&game, Limits::time(Duration::from_millis(200)));
+    assert!(result.best_move.is_some());
+    assert!(start.elapsed() < Duration::from_secs(2));
+}
+
+#[test]
+fn levels_search_deeper_and_longer() {
+    assert_eq!(Limits::level(0), Limits::level(1));
+    assert_eq!(Limits::level(MAX_LEVEL + 1), Limits::level(MAX_LEVEL));
+    assert_eq!(Limits::level(3).depth, Some(3));
+    assert!(Limits::level(2).time < Limits::level(3).time);
+}
@@ -0,0 +1,91 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::thread::sleep;
+use std::time::Duration;
+
+struct Engine {
+    process: Child,
+    input: ChildStdin,
+    output: BufReader<ChildStdout>,
+}
+
+impl Engine {
+    fn start() -> Self {
+        let mut process = Command::new(env!("CARGO_BIN_EXE_uci"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let input = process.stdin.take().unwrap();
+        let output = BufReader::new(process.stdout.take().unwrap());
+        Engine {
+            process,
+            input,
+            output,
+        }
+    }
+
+    fn send(&mut self, command: &str) {
+        writeln!(self.input, "{}", command).unwrap();
+    }
+
+    /// Returns the lines printed up to the first one starting with prefix, included
+    fn read_until(&mut self, prefix: &str) -> Vec<String> {
+        let mut lines = vec![];
+        loop {
+            let mut line = String::new();
+            assert!(self.output.read_line(&mut line).unwrap() > 0);
+            lines.push(String::from(line.trim()));
+            if line.starts_with(prefix) {
+                return lines;
+            }
+        }
+    }
+}
+
+impl Drop for Engine {
+    fn drop(&mut self) {
+        self.send("quit");
+        self.process.wait().unwrap();
+    }
+}
+
+#[test]
+fn handshake() {
+    let mut engine = Engine::start();
+    engine.send("uci");
+    let lines = engine.read_until("uciok");
+    assert!(lines.iter().any(|line| line.starts_with("id name")));
+    engine.send("isready");
+    assert_eq!(engine.read_until("readyok"), vec!["readyok"]);
+}
+
+#[test]
+fn searches_the_position_after_the_moves() {
+    let mut engine = Engine::start();
+    engine.send("ucinewgame");
+    // fool's mate is on the board after f3 e5 g4
+    engine.send("position startpos moves f2f3 e7e5 g2g4");
+    engine.send("go depth 2");
+    let lines = engine.read_until("bestmove");
+    assert!(lines[0].starts_with("info depth 1 score mate 1"));
+    assert_eq!(lines.last().unwrap(), "bestmove d8h4");
+
+    engine.send("position fen 6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1");
+    engine.send("go wtime 1000 btime 1000");
+    assert_eq!(
+        engine.read_until("bestmove").last().unwrap(),
+        "bestmove a1a8"
+    );
+}
+
+#[test]
+fn infinite_search_waits_for_stop() {
+    let mut engine = Engine::start();
+    engine.send("position startpos");
+    engine.send("go infinite");
+    sleep(Duration::from_millis(100));
+    engine.send("stop");
+    let lines = engine.read_until("bestmove");
+    assert!(lines.last().unwrap().len() > "bestmove ".len());
+}
@@ -0,0 +1,94 @@
+use chess::chess::Chess;
+use chess::error::MoveError;
+use chess::outcome::GameStatus;
+use chess::piece::Color;
+
+/// Plays the moves, takes them all back and checks every position on the way
+fn undo_all(fen: &str, moves: &[&str]) {
+    let mut game = Chess::from_fen(fen).unwrap();
+    let mut fens = vec![game.to_fen()];
+    for san in moves {
+        game.play_san(san).unwrap();
+        fens.push(game.to_fen());
+    }
+    fens.pop();
+    while let Some(fen) = fens.pop() {
+        game.undo().unwrap();
+        assert_eq!(game.to_fen(), fen);
+    }
+    assert!(game.get_moves().is_empty());
+}
+
+#[test]
+fn undo_restores_captured_pieces() {
+    undo_all(
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        &["e4", "d5", "exd5", "Qxd5", "Nc3", "Qxg2", "Bxg2"],
+    );
+}
+
+#[test]
+fn undo_restores_castling_rights_and_rooks() {
+    undo_all(
+        "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1",
+        &["O-O", "O-O-O", "Rf2", "Rxd2", "Rxd2"],
+    );
+    undo_all("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", &["Rxa8+", "Ke7"]);
+}
+
+#[test]
+fn undo_restores_en_passant_and_promotions() {
+    undo_all(
+        "4k3/1P6/8/8/3p4/8/4P3/4K3 w - - 0 1",
+        &["e4", "dxe3", "b8=Q+", "Kd7", "Qb5+"],
+    );
+}
+
+#[test]
+fn undo_and_redo() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    assert_eq!(game.undo(), Err(MoveError::NothingToUndo));
+    assert_eq!(game.redo(), Err(MoveError::NothingToRedo));
+    for san in ["f3", "e5", "g4", "Qh4#"] {
+        game.play_san(san).unwrap();
+    }
+    let checkmate = game.to_fen();
+    assert_eq!(game.undo().unwrap().to_string(), "d8h4");
+    assert_eq!(game.undo().unwrap().to_string(), "g2g4");
+    assert_eq!(game.get_moves(), &["f3", "e5"]);
+
+    game.redo().unwrap();
+    let outcome = game.redo().unwrap();
+    assert_eq!(outcome.status, GameStatus::Checkmate);
+    assert_eq!(game.to_fen(), checkmate);
+    assert_eq!(game.get_moves(), &["f3", "e5", "g4", "Qh4#"]);
+    assert!(game.redo().is_err());
+}
+
+#[test]
+fn undo_resumes_a_finished_game() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    for san in ["f3", "e5", "g4", "Qh4#"] {
+        game.play_san(san).unwrap();
+    }
+    assert!(matches!(game.play_uci("a2a3"), Err(MoveError::GameOver)));
+    game.undo().unwrap();
+    game.play_san("Qe7").unwrap();
+    assert!(game.redo().is_err());
+    assert_eq!(game.get_moves(), &["f3", "e5", "g4", "Qe7"]);
+}
+
+#[test]
+fn failed_redo_keeps_the_move() {
+    let mut game = Chess::new(String::from("white"), String::from("black"));
+    game.play_san("e4").unwrap();
+    game.play_san("e5").unwrap();
+    game.undo().unwrap();
+    game.resign(Color::White).unwrap();
+    assert_eq!(game.redo(), Err(MoveError::GameOver));
+    // taking back e4 resumes the game, both moves can be played again
+    game.undo().unwrap();
+    game.redo().unwrap();
+    game.redo().unwrap();
+    assert_eq!(game.get_moves(), &["e4", "e5"]);
+}